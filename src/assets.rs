@@ -0,0 +1,130 @@
+use crate::camera::MenuCamera;
+use crate::GameState;
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+
+/// Sprite/image handles shared across states.
+#[derive(Default)]
+pub(crate) struct Images {
+    pub(crate) player_sheet: Handle<Image>,
+}
+
+/// Texture atlas layouts built from the images above.
+#[derive(Default)]
+pub(crate) struct Layouts {
+    pub(crate) player_atlas: Handle<TextureAtlas>,
+}
+
+/// Fonts shared across states.
+#[derive(Default)]
+pub(crate) struct Fonts {
+    pub(crate) joystix: Handle<Font>,
+}
+
+/// Audio handles shared across states.
+#[derive(Default)]
+pub(crate) struct Sounds {}
+
+/// Cache of every handle loaded once at startup so states don't each hit the
+/// `AssetServer` themselves.
+#[derive(Default)]
+pub(crate) struct AssetLoader {
+    pub(crate) images: Images,
+    pub(crate) layouts: Layouts,
+    pub(crate) fonts: Fonts,
+    pub(crate) sounds: Sounds,
+}
+
+pub(crate) struct AssetLoaderPlugin;
+
+#[derive(Component)]
+pub(crate) struct LoadingUIElement;
+
+impl Plugin for AssetLoaderPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AssetLoader>()
+            .add_enter_system(GameState::Loading, load_assets)
+            .add_enter_system(GameState::Loading, setup_loading)
+            .add_system_set(
+                ConditionSet::new()
+                    .run_in_state(GameState::Loading)
+                    .with_system(wait_for_assets)
+                    .into(),
+            )
+            .add_exit_system(GameState::Loading, despawn_loading);
+    }
+}
+
+/// Blank placeholder screen shown while `load_assets` is in flight. Can't
+/// use `AssetLoader`'s own font here, since that's exactly what's still
+/// loading, so this is just a plain overlay with no text.
+fn setup_loading(mut commands: Commands) {
+    commands.spawn_bundle(Camera2dBundle::default()).insert(MenuCamera);
+
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                ..default()
+            },
+            color: Color::rgb(0.05, 0.05, 0.05).into(),
+            ..default()
+        })
+        .insert(LoadingUIElement);
+}
+
+fn despawn_loading(
+    mut commands: Commands,
+    camera_query: Query<Entity, With<MenuCamera>>,
+    ui_query: Query<Entity, With<LoadingUIElement>>,
+) {
+    camera_query.for_each(|camera| {
+        commands.entity(camera).despawn();
+    });
+
+    ui_query.for_each(|element| {
+        commands.entity(element).despawn_recursive();
+    });
+}
+
+/// Kick off every asset load once, on entering `GameState::Loading`.
+pub(crate) fn load_assets(
+    mut asset_loader: ResMut<AssetLoader>,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+) {
+    asset_loader.images.player_sheet = asset_server.load("characters/sprite_movement.png");
+
+    let texture_atlas = TextureAtlas::from_grid(
+        asset_loader.images.player_sheet.clone(),
+        Vec2::new(64.0, 64.0),
+        4,
+        4,
+    );
+    asset_loader.layouts.player_atlas = texture_atlases.add(texture_atlas);
+
+    asset_loader.fonts.joystix = asset_server.load("buttons/joystix monospace.ttf");
+}
+
+/// Block in `GameState::Loading` until every handle registered above reports
+/// `Loaded`, then hand off to the main menu.
+pub(crate) fn wait_for_assets(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    asset_loader: Res<AssetLoader>,
+) {
+    let handles = [
+        asset_loader.images.player_sheet.clone_untyped(),
+        asset_loader.fonts.joystix.clone_untyped(),
+    ];
+
+    if asset_server.get_group_load_state(handles.iter().map(|handle| handle.id))
+        == LoadState::Loaded
+    {
+        // Hand off to the main menu rather than straight to `StartPlaying`;
+        // the menu's own handlers still drive that transition from here,
+        // same as before this state existed.
+        commands.insert_resource(NextState(GameState::Start));
+    }
+}
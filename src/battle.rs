@@ -1,5 +1,14 @@
+use crate::assets::AssetLoader;
 use crate::backgrounds::Tile;
 use crate::camera::{MenuCamera, SlidesCamera};
+use crate::boss_summon::{maybe_summon_reinforcements, ActiveEnemies, HasSummoned, SummonPool};
+use crate::challenge::{do_challenge, CombatConfig, CombatModel, BASE_BODY};
+use crate::dodge::{dodge_value, roll_dodge, DodgeTracker};
+use crate::encounter_table::EncounterTables;
+use crate::enemy_ai::{choose_enemy_action, BattleState};
+use crate::equipment::{equipment_bonuses, DefenseBonus, Equipped, MeleePowerBonus};
+use crate::initiative::{tick_initiative, InitiativeTracker, MyTurn, Speed};
+use crate::status_effects::{ActiveEffects, StatusEffectKind};
 use crate::monster::{
     get_monster_sprite_for_type, Boss, Defense, Element, Enemy, Health, Level, MonsterStats,
     PartyMonster, SelectedMonster, Strength,
@@ -11,8 +20,60 @@ use crate::GameState;
 use bevy::prelude::*;
 use iyes_loopless::prelude::*;
 use rand::*;
+use std::collections::VecDeque;
 
 const BATTLE_BACKGROUND: &str = "backgrounds/battlescreen_desert_1.png";
+const BATTLE_LOG_CAPACITY: usize = 8;
+
+/// Severity tag used to color a battle log line.
+#[derive(Clone, Copy)]
+pub(crate) enum LogSeverity {
+    Normal,
+    Crit,
+    Faint,
+    Reward,
+}
+
+impl LogSeverity {
+    fn color(self) -> Color {
+        match self {
+            LogSeverity::Normal => Color::WHITE,
+            LogSeverity::Crit => Color::ORANGE_RED,
+            LogSeverity::Faint => Color::GRAY,
+            LogSeverity::Reward => Color::GOLD,
+        }
+    }
+}
+
+/// Bounded scrolling log of battle events, rendered in `BattleLogText`
+/// instead of being silently traced with `info!`.
+#[derive(Default)]
+pub(crate) struct BattleLog {
+    entries: VecDeque<(String, LogSeverity)>,
+}
+
+impl BattleLog {
+    pub(crate) fn push(&mut self, message: String, severity: LogSeverity) {
+        if self.entries.len() >= BATTLE_LOG_CAPACITY {
+            self.entries.pop_back();
+        }
+        self.entries.push_front((message, severity));
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// Push a battle event onto the `BattleLog` instead of just tracing it.
+macro_rules! log_event {
+    ($log:expr, $severity:expr, $($arg:tt)*) => {
+        $log.push(format!($($arg)*), $severity);
+    };
+}
+
+#[derive(Component)]
+pub(crate) struct BattleLogText;
 
 #[derive(Component)]
 pub(crate) struct BattleBackground;
@@ -26,6 +87,12 @@ pub(crate) struct PlayerMonster;
 #[derive(Component)]
 pub(crate) struct EnemyMonster;
 
+/// Marks an `Enemy` entity that already has its sprite bundle, so
+/// `spawn_enemy_monster` doesn't re-spawn one every frame and so
+/// reinforcements (which arrive pre-sprited) are left alone.
+#[derive(Component)]
+pub(crate) struct EnemySpriteReady;
+
 // Unit structs to help identify the specific UI components for player's or enemy's monster health/level
 // since there may be many Text components
 #[derive(Component)]
@@ -47,11 +114,17 @@ pub(crate) struct BattlePlugin;
 
 impl Plugin for BattlePlugin {
     fn build(&self, app: &mut App) {
-        app.add_enter_system_set(
+        app.init_resource::<BattleLog>()
+            .init_resource::<InitiativeTracker>()
+            .init_resource::<ActiveEnemies>()
+            .init_resource::<DodgeTracker>()
+            .init_resource::<CombatConfig>()
+            .add_enter_system_set(
             GameState::Battle,
             SystemSet::new()
                 .with_system(setup_battle)
-                .with_system(setup_battle_stats),
+                .with_system(setup_battle_stats)
+                .with_system(roll_encounter),
         )
         .add_system_set(
             ConditionSet::new()
@@ -61,13 +134,33 @@ impl Plugin for BattlePlugin {
                 .with_system(spawn_player_monster)
                 .with_system(spawn_enemy_monster)
                 .with_system(update_battle_stats)
+                .with_system(tick_initiative)
                 .with_system(key_press_handler)
+                .with_system(render_battle_log)
+                .with_system(crate::game_over::lose_game)
+                .with_system(toggle_combat_model)
                 .into(),
         )
         .add_exit_system(GameState::Battle, despawn_battle);
     }
 }
 
+/// Flip between `CombatModel::Arithmetic` and `CombatModel::Dice`. Debug-only
+/// toggle in the spirit of the F3 debug overlay -- there's no settings menu
+/// for combat model yet, so F4 is the only way to reach the dice model short
+/// of constructing `CombatConfig { model: CombatModel::Dice }` directly.
+fn toggle_combat_model(input: Res<Input<KeyCode>>, mut combat_config: ResMut<CombatConfig>) {
+    if !input.just_pressed(KeyCode::F4) {
+        return;
+    }
+
+    combat_config.model = match combat_config.model {
+        CombatModel::Arithmetic => CombatModel::Dice,
+        CombatModel::Dice => CombatModel::Arithmetic,
+    };
+    info!("Combat model switched to {:?}", combat_config.model);
+}
+
 macro_rules! end_battle {
     ($commands:expr, $game_progress:expr, $my_monster:expr, $enemy_monster:expr) => {
         // remove the monster from the enemy stats
@@ -89,11 +182,22 @@ macro_rules! monster_level_up {
             .get_mut(&$my_monster)
             .unwrap();
         stats.lvl.level += 1 * $up_by;
-        stats.hp.max_health += 10 * $up_by;
-        stats.hp.health = stats.hp.max_health as isize;
-        stats.stg.atk += 2 * $up_by;
-        stats.stg.crt += 5 * $up_by;
-        stats.def.def += 1 * $up_by;
+        // Recompute skills/pools from a per-species GrowthCurve (keyed on
+        // the monster's own Element) instead of applying the same flat
+        // increments to every monster.
+        let curve = crate::stats::GrowthCurve::for_element(stats.typing);
+        let mut skills = crate::stats::Skills::new(stats.lvl.level);
+        let mut health_pool = crate::stats::Pool {
+            max: stats.hp.max_health as i32,
+            current: stats.hp.health as i32,
+        };
+        let mut crt = stats.stg.crt;
+        crate::stats::level_up(&mut skills, &mut health_pool, &mut crt, &curve, stats.lvl.level, $up_by);
+        stats.hp.max_health = health_pool.max as u32;
+        stats.hp.health = health_pool.current as isize;
+        stats.stg.atk = crate::stats::atk_from_skills(&skills);
+        stats.def.def = crate::stats::def_from_skills(&skills);
+        stats.stg.crt = crt;
         // we have to remove the old stats and add the new one
         // because we cannot change the stats in place
         $commands.entity($my_monster).remove::<MonsterStats>();
@@ -137,9 +241,81 @@ pub(crate) fn setup_battle(
 
 // -----------------------------------------------------------------------------------------------------------
 
+/// Build a fresh `MonsterStats` for a monster of `element` at `level`,
+/// using the same per-`Element` `GrowthCurve` `monster_level_up!` levels
+/// existing monsters with. Also used by `maybe_summon_reinforcements` so
+/// mid-battle reinforcements scale the same way a freshly rolled enemy does.
+pub(crate) fn build_enemy_stats(element: Element, level: i32) -> MonsterStats {
+    let curve = crate::stats::GrowthCurve::for_element(element);
+    let skills = crate::stats::Skills::for_curve(&curve, level);
+
+    let mut stats = MonsterStats {
+        typing: element,
+        lvl: Level { level },
+        ..Default::default()
+    };
+    stats.hp.max_health = curve.health_for_level(level) as u32;
+    stats.hp.health = stats.hp.max_health as isize;
+    stats.stg.atk = crate::stats::atk_from_skills(&skills);
+    stats.stg.crt = curve.crt_for_level(level);
+    stats.def.def = crate::stats::def_from_skills(&skills);
+    stats
+}
+
+/// If no `Enemy` entity already exists for this battle, roll one from
+/// `EncounterTables` instead of leaving the table (and `roll_for_level`)
+/// registered but unused.
+pub(crate) fn roll_encounter(
+    mut commands: Commands,
+    encounter_tables: Res<EncounterTables>,
+    mut game_progress: ResMut<GameProgress>,
+    mut active_enemies: ResMut<ActiveEnemies>,
+    existing_enemies: Query<Entity, With<Enemy>>,
+) {
+    if !existing_enemies.is_empty() {
+        return;
+    }
+
+    let entry = match encounter_tables
+        .roll_for_level(game_progress.current_level, &mut rand::thread_rng())
+    {
+        Some(entry) => entry,
+        None => return,
+    };
+
+    let mut stats = build_enemy_stats(entry.element, game_progress.current_level as i32);
+    crate::run_clock::scale_stats_for_difficulty(&mut stats, game_progress.difficulty_multiplier);
+
+    let mut enemy_commands = commands.spawn();
+    enemy_commands
+        .insert_bundle(stats.clone())
+        .insert(Enemy)
+        .insert(entry.element)
+        .insert(Speed::for_element(entry.element))
+        .insert(ActiveEffects::default());
+
+    if entry.is_boss {
+        // Reinforcements are drawn from every element other than the
+        // boss's own, and called in once the boss drops to half health.
+        let reinforcement_elements: Vec<Element> = [Element::Normal, Element::Fire, Element::Water, Element::Earth, Element::Air]
+            .into_iter()
+            .filter(|element| *element != entry.element)
+            .collect();
+        enemy_commands.insert(Boss).insert(SummonPool {
+            elements: reinforcement_elements,
+            summon_threshold: 0.5,
+        });
+    }
+
+    let enemy = enemy_commands.id();
+
+    game_progress.enemy_stats.insert(enemy, stats);
+    active_enemies.entities.push(enemy);
+}
+
 pub(crate) fn setup_battle_stats(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
+    asset_loader: Res<AssetLoader>,
     mut set: ParamSet<(
         Query<&mut Level, With<SelectedMonster>>,
         Query<&mut Level, With<Enemy>>,
@@ -163,14 +339,14 @@ pub(crate) fn setup_battle_stats(
                 TextSection::new(
                     "Health:",
                     TextStyle {
-                        font: asset_server.load("buttons/joystix monospace.ttf"),
+                        font: asset_loader.fonts.joystix.clone(),
                         font_size: 40.0,
                         color: Color::BLACK,
                     },
                 ),
                 // health of player's monster
                 TextSection::from_style(TextStyle {
-                    font: asset_server.load("buttons/joystix monospace.ttf"),
+                    font: asset_loader.fonts.joystix.clone(),
                     font_size: 40.0,
                     color: Color::BLACK,
                 }),
@@ -197,7 +373,7 @@ pub(crate) fn setup_battle_stats(
                 TextSection::new(
                     "Level:",
                     TextStyle {
-                        font: asset_server.load("buttons/joystix monospace.ttf"),
+                        font: asset_loader.fonts.joystix.clone(),
                         font_size: 40.0,
                         color: Color::BLACK,
                     },
@@ -206,7 +382,7 @@ pub(crate) fn setup_battle_stats(
                 TextSection::new(
                     my_lvl.to_string(),
                     TextStyle {
-                        font: asset_server.load("buttons/joystix monospace.ttf"),
+                        font: asset_loader.fonts.joystix.clone(),
                         font_size: 40.0,
                         color: Color::BLACK,
                     },
@@ -234,14 +410,14 @@ pub(crate) fn setup_battle_stats(
                 TextSection::new(
                     "Health:",
                     TextStyle {
-                        font: asset_server.load("buttons/joystix monospace.ttf"),
+                        font: asset_loader.fonts.joystix.clone(),
                         font_size: 40.0,
                         color: Color::BLACK,
                     },
                 ),
                 // health of enemy's monster
                 TextSection::from_style(TextStyle {
-                    font: asset_server.load("buttons/joystix monospace.ttf"),
+                    font: asset_loader.fonts.joystix.clone(),
                     font_size: 40.0,
                     color: Color::BLACK,
                 }),
@@ -269,7 +445,7 @@ pub(crate) fn setup_battle_stats(
                 TextSection::new(
                     "Level:",
                     TextStyle {
-                        font: asset_server.load("buttons/joystix monospace.ttf"),
+                        font: asset_loader.fonts.joystix.clone(),
                         font_size: 40.0,
                         color: Color::BLACK,
                     },
@@ -278,7 +454,7 @@ pub(crate) fn setup_battle_stats(
                 TextSection::new(
                     enemy_lvl.to_string(),
                     TextStyle {
-                        font: asset_server.load("buttons/joystix monospace.ttf"),
+                        font: asset_loader.fonts.joystix.clone(),
                         font_size: 40.0,
                         color: Color::BLACK,
                     },
@@ -297,30 +473,80 @@ pub(crate) fn setup_battle_stats(
         )
         .insert(EnemyLevel)
         .insert(BattleUIElement);
+
+    // Bottom-anchored scrolling log of battle events.
+    commands
+        .spawn_bundle(TextBundle::from_section(
+            "",
+            TextStyle {
+                font: asset_loader.fonts.joystix.clone(),
+                font_size: 20.0,
+                color: Color::WHITE,
+            },
+        ))
+        .insert(Style {
+            align_self: AlignSelf::FlexEnd,
+            position_type: PositionType::Absolute,
+            position: UiRect {
+                bottom: Val::Px(10.0),
+                left: Val::Px(15.0),
+                ..default()
+            },
+            ..default()
+        })
+        .insert(BattleLogText)
+        .insert(BattleUIElement);
+}
+
+/// Rewrite the `BattleLogText` section each frame from the `BattleLog` deque.
+pub(crate) fn render_battle_log(
+    battle_log: Res<BattleLog>,
+    mut text_query: Query<&mut Text, With<BattleLogText>>,
+) {
+    for mut text in &mut text_query {
+        let mut section = text.sections[0].clone();
+        section.value = battle_log
+            .entries
+            .iter()
+            .map(|(line, _)| line.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        // Color the whole block to the most recent line's severity.
+        if let Some((_, severity)) = battle_log.entries.front() {
+            section.style.color = severity.color();
+        }
+        text.sections[0] = section;
+    }
 }
 
 pub(crate) fn update_battle_stats(
     _commands: Commands,
     _asset_server: Res<AssetServer>,
-    mut set: ParamSet<(
-        Query<&mut Health, With<SelectedMonster>>,
-        Query<&mut Health, With<Enemy>>,
-    )>,
+    player_health_query: Query<&Health, With<SelectedMonster>>,
+    enemy_health_query: Query<&Health, With<Enemy>>,
+    active_enemies: Res<ActiveEnemies>,
     mut enemy_health_text_query: Query<&mut Text, (With<EnemyHealth>, Without<PlayerHealth>)>,
     mut player_health_text_query: Query<&mut Text, (With<PlayerHealth>, Without<EnemyHealth>)>,
 ) {
-    let mut my_health = 0;
-    let mut enemy_health = 0;
-    for my_monster in set.p0().iter_mut() {
-        my_health = my_monster.health;
-    }
-
-    for enemy_monster in set.p1().iter_mut() {
-        enemy_health = enemy_monster.health;
-    }
+    let my_health = player_health_query
+        .iter()
+        .next()
+        .map(|health| health.health)
+        .unwrap_or(0);
+
+    // Render every active enemy's health, not just whichever `With<Enemy>`
+    // entity a `.single()` happened to find, now that a boss fight can have
+    // reinforcements alive alongside it.
+    let enemy_health_display = active_enemies
+        .entities
+        .iter()
+        .filter_map(|&entity| enemy_health_query.get(entity).ok())
+        .map(|health| health.health.to_string())
+        .collect::<Vec<_>>()
+        .join(" / ");
 
     for mut text in &mut enemy_health_text_query {
-        text.sections[1].value = format!("{}", enemy_health);
+        text.sections[1].value = enemy_health_display.clone();
     }
 
     for mut text in &mut player_health_text_query {
@@ -376,31 +602,49 @@ pub(crate) fn spawn_enemy_monster(
         (&Transform, Entity),
         (With<Camera2d>, Without<MenuCamera>, Without<SlidesCamera>),
     >,
-    selected_type_query: Query<&Element, (Without<SelectedMonster>, With<Enemy>)>,
+    mut active_enemies: ResMut<ActiveEnemies>,
+    unsprited_enemies: Query<
+        (Entity, &Element),
+        (With<Enemy>, Without<SelectedMonster>, Without<EnemySpriteReady>),
+    >,
 ) {
     if cameras.is_empty() {
         error!("No spawned camera...?");
         return;
     }
 
-    if selected_type_query.is_empty() {
-        error!("No selected monster...?");
+    if unsprited_enemies.is_empty() {
         return;
     }
 
-    let selected_type = selected_type_query.single();
-
     let (ct, _) = cameras.single();
 
-    commands
-        .spawn_bundle(SpriteBundle {
-            texture: asset_server.load(&get_monster_sprite_for_type(*selected_type)),
-            transform: Transform::from_xyz(ct.translation.x + 400., ct.translation.y - 100., 1.),
-            ..default()
-        })
-        .insert(EnemyMonster)
-        .insert(Monster);
-    // .insert(monster_info.clone());
+    // Give every freshly-arrived enemy (the initial one, plus any
+    // reinforcements not already sprited elsewhere) its own sprite,
+    // offset further back so they don't stack on top of each other, and
+    // register it in `ActiveEnemies` so the fight tracks every combatant
+    // instead of just whichever `With<Enemy>` entity `.single()` happened
+    // to find.
+    for (i, (enemy_entity, element)) in unsprited_enemies.iter().enumerate() {
+        if !active_enemies.entities.contains(&enemy_entity) {
+            active_enemies.entities.push(enemy_entity);
+        }
+        let offset_x = 400. + 80.0 * i as f32;
+        commands
+            .entity(enemy_entity)
+            .insert_bundle(SpriteBundle {
+                texture: asset_server.load(&get_monster_sprite_for_type(*element)),
+                transform: Transform::from_xyz(
+                    ct.translation.x + offset_x,
+                    ct.translation.y - 100.,
+                    1.,
+                ),
+                ..default()
+            })
+            .insert(EnemyMonster)
+            .insert(Monster)
+            .insert(EnemySpriteReady);
+    }
 }
 
 pub(crate) fn despawn_battle(
@@ -408,7 +652,15 @@ pub(crate) fn despawn_battle(
     background_query: Query<Entity, With<BattleBackground>>,
     monster_query: Query<Entity, With<Monster>>,
     battle_ui_element_query: Query<Entity, With<BattleUIElement>>,
+    mut battle_log: ResMut<BattleLog>,
+    mut active_enemies: ResMut<ActiveEnemies>,
+    mut dodge_tracker: ResMut<DodgeTracker>,
 ) {
+    // Clear stale lines/reinforcements so they don't leak into the next encounter.
+    battle_log.clear();
+    active_enemies.entities.clear();
+    dodge_tracker.clear();
+
     if background_query.is_empty() {
         error!("background is here!");
     }
@@ -427,6 +679,7 @@ pub(crate) fn despawn_battle(
             .remove_bundle::<SpriteBundle>()
             .remove::<PlayerMonster>()
             .remove::<EnemyMonster>()
+            .remove::<EnemySpriteReady>()
             .remove::<Monster>();
     });
 
@@ -446,7 +699,16 @@ pub(crate) fn key_press_handler(
     mut game_progress: ResMut<GameProgress>,
     // placeholder for another resource dedicated to battle
     mut my_monster: Query<
-        (&mut Health, &mut Strength, &mut Defense, Entity, &Element),
+        (
+            &mut Health,
+            &mut Strength,
+            &mut Defense,
+            Entity,
+            &Element,
+            Option<&MyTurn>,
+            Option<&Speed>,
+            Option<&mut ActiveEffects>,
+        ),
         (With<SelectedMonster>, Without<Enemy>),
     >,
     mut enemy_monster: Query<
@@ -457,6 +719,11 @@ pub(crate) fn key_press_handler(
             Entity,
             Option<&Boss>,
             &Element,
+            Option<&MyTurn>,
+            Option<&SummonPool>,
+            Option<&HasSummoned>,
+            Option<&Speed>,
+            Option<&mut ActiveEffects>,
         ),
         (Without<SelectedMonster>, With<Enemy>),
     >,
@@ -465,6 +732,14 @@ pub(crate) fn key_press_handler(
         (With<PartyMonster>, Without<SelectedMonster>, Without<Enemy>),
     >,
     type_system: Res<TypeSystem>,
+    world_map: Res<crate::world::WorldMap>,
+    run_clock: Res<crate::run_clock::RunClock>,
+    mut battle_log: ResMut<BattleLog>,
+    melee_bonus_query: Query<(&Equipped, &MeleePowerBonus)>,
+    defense_bonus_query: Query<(&Equipped, &DefenseBonus)>,
+    mut active_enemies: ResMut<ActiveEnemies>,
+    mut dodge_tracker: ResMut<DodgeTracker>,
+    combat_config: Res<CombatConfig>,
     camera: Query<
         (&Transform, Entity),
         (With<Camera2d>, Without<MenuCamera>, Without<SlidesCamera>),
@@ -486,19 +761,69 @@ pub(crate) fn key_press_handler(
     let (transform, _) = camera.single();
 
     // Get player and enemy monster data out of the query
-    let (mut player_health, mut player_stg, player_def, player_entity, player_type) =
-        my_monster.single_mut();
+    let (
+        mut player_health,
+        mut player_stg,
+        mut player_def,
+        player_entity,
+        player_type,
+        player_turn,
+        player_speed,
+        mut player_active_effects,
+    ) = my_monster.single_mut();
+    let player_has_turn = player_turn.is_some();
+    let player_agility = player_speed.map(|speed| speed.0).unwrap_or(10);
+
+    // Fight the first entity in the queue rather than `.single_mut()`,
+    // which panics the moment a boss's reinforcements add a second
+    // `Enemy` entity. Enemies are fought one at a time in queue order;
+    // the rest wait their turn until the current one is defeated.
+    let current_enemy_entity = match active_enemies.entities.first().copied() {
+        Some(entity) => entity,
+        None => {
+            error!("No active enemy entity tracked!");
+            commands.insert_resource(NextState(GameState::Playing));
+            return;
+        }
+    };
+    let (
+        mut enemy_health,
+        mut enemy_stg,
+        mut enemy_def,
+        enemy_entity,
+        enemy_boss,
+        enemy_type,
+        enemy_turn,
+        summon_pool,
+        has_summoned,
+        enemy_speed,
+        mut enemy_active_effects,
+    ) = match enemy_monster.get_mut(current_enemy_entity) {
+        Ok(data) => data,
+        Err(_) => {
+            error!("Active enemy entity has no Enemy components!");
+            commands.insert_resource(NextState(GameState::Playing));
+            return;
+        }
+    };
+    let enemy_has_turn = enemy_turn.is_some();
+    let enemy_agility = enemy_speed.map(|speed| speed.0).unwrap_or(10);
 
-    let (mut enemy_health, enemy_stg, enemy_def, enemy_entity, enemy_boss, enemy_type) =
-        enemy_monster.single_mut();
+    // Note: `player_has_turn` does NOT gate the A/E/D input handling itself
+    // -- it only gates whether the player's half of an exchange lands
+    // (mirroring how `enemy_has_turn` gates the enemy's half below), and
+    // whether the 1/2 item keys do anything. Gating the whole handler here used to mean that
+    // whenever the enemy won initiative, the player's input was ignored
+    // entirely and nothing ever cleared the enemy's `MyTurn`, soft-locking
+    // the battle forever.
 
     if player_health.health <= 0 {
         let next_monster = game_progress.next_monster_cyclic(player_entity);
         if next_monster.is_none() {
-            info!("Your monster was defeated.");
+            log_event!(battle_log, LogSeverity::Faint, "Your monster was defeated.");
             end_battle!(commands, game_progress, player_entity, enemy_entity);
         } else {
-            info!("Your monster was defeated. Switching to next monster.");
+            log_event!(battle_log, LogSeverity::Faint, "Your monster was defeated. Switching to next monster.");
             commands.entity(player_entity).remove::<SelectedMonster>();
             commands
                 .entity(player_entity)
@@ -513,257 +838,130 @@ pub(crate) fn key_press_handler(
 
     if input.just_pressed(KeyCode::A) {
         // ATTACK HANDLER
-        // Actions:
-        // 0: attack 1: defend: 2: elemental: 3: special
-        let enemy_action = rand::thread_rng().gen_range(0..=3);
-        info!("You attack!");
-
-        if enemy_action == 0 {
-            info!("Enemy attacks!")
-        } else if enemy_action == 1 {
-            info!("Enemy defends!")
-        } else if enemy_action == 2 {
-            info!("Enemy uses an elemental attack!")
-        } else {
-            info!("Enemy uses its special ability!")
-        }
-
-        let str_buff_damage = if game_progress.turns_left_of_buff[0] > 0 {
-            info!("You will deal extra damage this turn.");
-            game_progress.turns_left_of_buff[0] -= 1;
-            game_progress.current_level
-        } else {
-            0
-        };
-
-        // Temporarily increase strength for the turn calculation
-        player_stg.atk += str_buff_damage;
-        let turn_result = calculate_turn(
-            &player_stg,
-            &player_def,
-            player_type,
+        resolve_attack(
             0,
-            &enemy_stg,
-            &enemy_def,
+            "You attack!".to_string(),
+            &mut commands,
+            &mut game_progress,
+            &mut battle_log,
+            &type_system,
+            &world_map,
+            &run_clock,
+            &mut active_enemies,
+            &mut dodge_tracker,
+            &combat_config,
+            &melee_bonus_query,
+            &defense_bonus_query,
+            &mut party_monsters,
+            &asset_server,
+            transform,
+            &mut player_health,
+            &mut player_stg,
+            &mut player_def,
+            player_entity,
+            player_type,
+            player_has_turn,
+            player_agility,
+            player_active_effects.as_deref_mut(),
+            &mut enemy_health,
+            &mut enemy_stg,
+            &mut enemy_def,
+            enemy_entity,
+            enemy_boss,
             enemy_type,
-            enemy_action,
-            *type_system,
+            enemy_has_turn,
+            summon_pool,
+            has_summoned.is_some(),
+            enemy_agility,
+            enemy_active_effects.as_deref_mut(),
         );
-        // Reset strength for next turn
-        player_stg.atk -= str_buff_damage;
-
-        player_health.health -= turn_result.1;
-        enemy_health.health -= turn_result.0;
-
-        if enemy_health.health <= 0 {
-            info!("Enemy monster defeated. Your monsters will level up!");
-            // at this point this monster is already "ours", we just need to register is with the resource
-            // get the stats from the monster
-            let mut new_monster_stats = *game_progress.enemy_stats.get(&enemy_entity).unwrap();
-            // Clamp health down so we don't keep boss health
-            new_monster_stats.hp.health = game_progress.current_level as isize * 10;
-            new_monster_stats.hp.max_health = game_progress.current_level * 10;
-            // remove the monster from the enemy stats
-            game_progress.enemy_stats.remove(&enemy_entity);
-            // add the monster to the monster bag
-            commands.entity(enemy_entity).insert(PartyMonster);
-            game_progress.new_monster(enemy_entity, new_monster_stats);
-            // TODO: see the discrepancy between the type we see and the type we get
-            info!(
-                "new member type: {:?}",
-                game_progress
-                    .monster_entity_to_stats
-                    .get(&enemy_entity)
-                    .unwrap()
-                    .typing
-            );
-            // update game progress
-            // check for boss
-            if enemy_boss.is_some() {
-                info!("Boss defeated!");
-                game_progress.get_quest_rewards(*enemy_type);
-                game_progress.win_boss();
-                // if boss level up twice
-                for pm in party_monsters.iter_mut() {
-                    monster_level_up!(commands, game_progress, pm.3, 1);
-                }
-                monster_level_up!(commands, game_progress, player_entity, 1);
-                monster_level_up!(commands, game_progress, enemy_entity, 1);
-                commands.entity(enemy_entity).remove::<Boss>();
-
-                // Spawn an NPC if enemy_boss is some and we won
-                let new_quest = Quest::random();
-                info!("Someone appears in the dust!");
-                commands
-                    .spawn_bundle(SpriteBundle {
-                        texture: asset_server.load(NPC_PATH),
-                        transform: Transform::from_xyz(
-                            transform.translation.x,
-                            transform.translation.y,
-                            0.,
-                        ),
-                        ..default()
-                    })
-                    .insert(NPC { quest: new_quest });
-            } else {
-                game_progress.win_battle();
-                game_progress.get_quest_rewards(*enemy_type);
-                // if not boss level up once
-                for pm in party_monsters.iter_mut() {
-                    monster_level_up!(commands, game_progress, pm.3, 1);
-                }
-                monster_level_up!(commands, game_progress, player_entity, 1);
-                monster_level_up!(commands, game_progress, enemy_entity, 1);
-            }
-            end_battle!(commands, game_progress, player_entity, enemy_entity);
-        } else if player_health.health <= 0 {
-            game_progress.num_living_monsters -= 1;
-            let next_monster = game_progress.next_monster_cyclic(player_entity);
-            if next_monster.is_none() {
-                info!("Your monster was defeated.");
-                end_battle!(commands, game_progress, player_entity, enemy_entity);
-            } else {
-                info!("Your monster was defeated. Switching to next monster.");
-                commands.entity(player_entity).remove::<SelectedMonster>();
-                commands
-                    .entity(player_entity)
-                    .remove_bundle::<SpriteBundle>();
-                commands.entity(player_entity).remove::<PlayerMonster>();
-                commands.entity(player_entity).remove::<Monster>();
-                commands
-                    .entity(*next_monster.unwrap())
-                    .insert(SelectedMonster);
-            }
-        }
     } else if input.just_pressed(KeyCode::E) {
         // ELEMENTAL ATTACK HANDLER
-        // Actions:
-        // 0: attack 1: defend: 2: elemental: 3: special
-        let enemy_action = rand::thread_rng().gen_range(0..=3);
-        info!("You use your type {:?} elemental attack!", player_type);
-
-        if enemy_action == 0 {
-            info!("Enemy attacks!")
-        } else if enemy_action == 1 {
-            info!("Enemy defends!")
-        } else if enemy_action == 2 {
-            info!("Enemy uses an elemental attack!")
-        } else {
-            info!("Enemy uses its special ability!")
-        }
-
-        let str_buff_damage = if game_progress.turns_left_of_buff[0] > 0 {
-            info!("You will deal extra damage this turn.");
-            game_progress.turns_left_of_buff[0] -= 1;
-            game_progress.current_level
-        } else {
-            0
-        };
-
-        // Temporarily increase strength for the turn calculation
-        player_stg.atk += str_buff_damage;
-        let turn_result = calculate_turn(
-            &player_stg,
-            &player_def,
-            player_type,
+        resolve_attack(
             2,
-            &enemy_stg,
-            &enemy_def,
+            format!("You use your type {:?} elemental attack!", player_type),
+            &mut commands,
+            &mut game_progress,
+            &mut battle_log,
+            &type_system,
+            &world_map,
+            &run_clock,
+            &mut active_enemies,
+            &mut dodge_tracker,
+            &combat_config,
+            &melee_bonus_query,
+            &defense_bonus_query,
+            &mut party_monsters,
+            &asset_server,
+            transform,
+            &mut player_health,
+            &mut player_stg,
+            &mut player_def,
+            player_entity,
+            player_type,
+            player_has_turn,
+            player_agility,
+            player_active_effects.as_deref_mut(),
+            &mut enemy_health,
+            &mut enemy_stg,
+            &mut enemy_def,
+            enemy_entity,
+            enemy_boss,
             enemy_type,
-            enemy_action,
-            *type_system,
+            enemy_has_turn,
+            summon_pool,
+            has_summoned.is_some(),
+            enemy_agility,
+            enemy_active_effects.as_deref_mut(),
         );
-        // Reset strength for next turn
-        player_stg.atk -= str_buff_damage;
-
-        player_health.health -= turn_result.1;
-        enemy_health.health -= turn_result.0;
-
-        if enemy_health.health <= 0 {
-            info!("Enemy monster defeated. Your monsters will level up!");
-            // at this point this monster is already "ours", we just need to register is with the resource
-            // get the stats from the monster
-            let mut new_monster_stats = *game_progress.enemy_stats.get(&enemy_entity).unwrap();
-            // Clamp health down so we don't keep boss health
-            new_monster_stats.hp.health = game_progress.current_level as isize * 10;
-            new_monster_stats.hp.max_health = game_progress.current_level * 10;
-            // remove the monster from the enemy stats
-            game_progress.enemy_stats.remove(&enemy_entity);
-            // add the monster to the monster bag
-            commands.entity(enemy_entity).insert(PartyMonster);
-            game_progress.new_monster(enemy_entity, new_monster_stats);
-            // TODO: see the discrepancy between the type we see and the type we get
-            info!(
-                "new member type: {:?}",
-                game_progress
-                    .monster_entity_to_stats
-                    .get(&enemy_entity)
-                    .unwrap()
-                    .typing
-            );
-            // update game progress
-            // check for boss
-            if enemy_boss.is_some() {
-                info!("Boss defeated!");
-                game_progress.win_boss();
-                game_progress.get_quest_rewards(*enemy_type);
-                // if boss level up twice
-                for pm in party_monsters.iter_mut() {
-                    monster_level_up!(commands, game_progress, pm.3, 1);
-                }
-                monster_level_up!(commands, game_progress, player_entity, 1);
-                monster_level_up!(commands, game_progress, enemy_entity, 1);
-                commands.entity(enemy_entity).remove::<Boss>();
-                // Spawn an NPC if enemy_boss is some and we won
-                let new_quest = Quest::random();
-                info!("Someone appears in the dust!");
-                commands
-                    .spawn_bundle(SpriteBundle {
-                        texture: asset_server.load(NPC_PATH),
-                        transform: Transform::from_xyz(
-                            transform.translation.x,
-                            transform.translation.y,
-                            0.,
-                        ),
-                        ..default()
-                    })
-                    .insert(NPC { quest: new_quest });
-            } else {
-                game_progress.win_battle();
-                game_progress.get_quest_rewards(*enemy_type);
-                // if not boss level up once
-                for pm in party_monsters.iter_mut() {
-                    monster_level_up!(commands, game_progress, pm.3, 1);
-                }
-                monster_level_up!(commands, game_progress, player_entity, 1);
-                monster_level_up!(commands, game_progress, enemy_entity, 1);
-            }
-            end_battle!(commands, game_progress, player_entity, enemy_entity);
-        } else if player_health.health <= 0 {
-            game_progress.num_living_monsters -= 1;
-            let next_monster = game_progress.next_monster_cyclic(player_entity);
-            if next_monster.is_none() {
-                info!("Your monster was defeated.");
-                end_battle!(commands, game_progress, player_entity, enemy_entity);
-            } else {
-                info!("Your monster was defeated. Switching to next monster.");
-                commands.entity(player_entity).remove::<SelectedMonster>();
-                commands
-                    .entity(player_entity)
-                    .remove_bundle::<SpriteBundle>();
-                commands.entity(player_entity).remove::<PlayerMonster>();
-                commands.entity(player_entity).remove::<Monster>();
-                commands
-                    .entity(*next_monster.unwrap())
-                    .insert(SelectedMonster);
-            }
-        }
     } else if input.just_pressed(KeyCode::Q) {
         // ABORT HANDLER
-        commands.entity(enemy_entity).remove::<Enemy>();
+        // Clear every tracked enemy, not just the current target, so
+        // fleeing a boss fight doesn't leave its reinforcements behind as
+        // dangling `Enemy` entities.
+        for tracked_enemy in active_enemies.entities.drain(..) {
+            commands.entity(tracked_enemy).remove::<Enemy>();
+        }
         commands.insert_resource(NextState(GameState::Playing));
     } else if input.just_pressed(KeyCode::D) {
         // DEFEND HANDLER
+        resolve_attack(
+            1,
+            "You guard!".to_string(),
+            &mut commands,
+            &mut game_progress,
+            &mut battle_log,
+            &type_system,
+            &world_map,
+            &run_clock,
+            &mut active_enemies,
+            &mut dodge_tracker,
+            &combat_config,
+            &melee_bonus_query,
+            &defense_bonus_query,
+            &mut party_monsters,
+            &asset_server,
+            transform,
+            &mut player_health,
+            &mut player_stg,
+            &mut player_def,
+            player_entity,
+            player_type,
+            player_has_turn,
+            player_agility,
+            player_active_effects.as_deref_mut(),
+            &mut enemy_health,
+            &mut enemy_stg,
+            &mut enemy_def,
+            enemy_entity,
+            enemy_boss,
+            enemy_type,
+            enemy_has_turn,
+            summon_pool,
+            has_summoned.is_some(),
+            enemy_agility,
+            enemy_active_effects.as_deref_mut(),
+        );
     } else if input.just_pressed(KeyCode::C) {
         // CYCLE HANDLER
         if my_monster.is_empty() {
@@ -773,9 +971,9 @@ pub(crate) fn key_press_handler(
         // They want to cycle their monster
         let next_monster = game_progress.next_monster_cyclic(player_entity);
         if next_monster.is_none() {
-            info!("No monster to cycle to.");
+            log_event!(battle_log, LogSeverity::Normal, "No monster to cycle to.");
         } else {
-            info!("Cycling to next monster in party.");
+            log_event!(battle_log, LogSeverity::Normal, "Cycling to next monster in party.");
             commands.entity(player_entity).remove::<SelectedMonster>();
             commands
                 .entity(player_entity)
@@ -788,8 +986,9 @@ pub(crate) fn key_press_handler(
         }
     } else if input.just_pressed(KeyCode::Key1) {
         // USE HEAL ITEM HANDLER
+        // Only the side holding MyTurn may act, same as the A/E/D handlers.
         // Must first check that they have enough healing items
-        if game_progress.player_inventory[0] > 0 {
+        if player_has_turn && game_progress.player_inventory[0] > 0 {
             // Remove the item, it is used now
             game_progress.player_inventory[0] -= 1;
 
@@ -811,79 +1010,504 @@ pub(crate) fn key_press_handler(
                 }
             }
 
-            // Now heal selected monster
-            if player_health.health + heal_amount > player_health.max_health as isize {
-                player_health.health = player_health.max_health as isize;
+            // Now heal selected monster, routed through a one-turn Regen
+            // effect so a consumable heal and a poison DoT tick through the
+            // same code path instead of each mutating health ad hoc.
+            if let Some(effects) = &mut player_active_effects {
+                effects.apply(StatusEffectKind::Regen, 1, heal_amount as i32);
+                effects.tick(&mut player_health, "You", &mut battle_log);
             } else {
-                player_health.health += heal_amount;
+                if player_health.health + heal_amount > player_health.max_health as isize {
+                    player_health.health = player_health.max_health as isize;
+                } else {
+                    player_health.health += heal_amount;
+                }
+                log_event!(battle_log, LogSeverity::Reward, "{} health restored.", heal_amount);
             }
-
-            info!("{} health restored.", heal_amount);
+            // Using an item spends the player's turn, same as A/E/D.
+            commands.entity(player_entity).remove::<MyTurn>();
         }
     } else if input.just_pressed(KeyCode::Key2) {
         // USE STRENGTH BUFF HANDLER
+        // Only the side holding MyTurn may act, same as the A/E/D handlers.
         // Check that we have a buff item
-        if game_progress.player_inventory[1] > 0 {
-            info!("You used a strength buff. The next five turns you will deal extra damage.");
+        if player_has_turn && game_progress.player_inventory[1] > 0 {
+            log_event!(battle_log, LogSeverity::Normal, "You used a strength buff. The next five turns you will deal extra damage.");
             // Decrement
             game_progress.player_inventory[1] -= 1;
-            // Make it so we have turns left of this buff
-            game_progress.turns_left_of_buff[0] = 5;
+            // Apply a 5-turn strength buff through ActiveEffects instead of
+            // the old single-slot turns_left_of_buff array.
+            if let Some(effects) = &mut player_active_effects {
+                effects.apply(StatusEffectKind::StrengthBuff, 5, game_progress.current_level);
+            } else {
+                warn!("Selected monster has no ActiveEffects component; buff was not applied.");
+            }
+            // Using an item spends the player's turn, same as A/E/D.
+            commands.entity(player_entity).remove::<MyTurn>();
         }
     }
 }
 
+/// Shared body of the A(ttack)/E(lemental)/D(efend) handlers in
+/// `key_press_handler`: pick the enemy's action, roll the exchange, apply
+/// it to both sides, and settle any resulting faint/victory/level-up.
+/// `action_code`/`you_message` are the only things that differ between the
+/// three callers (0/1/2 into `calculate_turn`, and what gets logged for the
+/// player's own action).
+#[allow(clippy::too_many_arguments)]
+fn resolve_attack(
+    action_code: usize,
+    you_message: String,
+    commands: &mut Commands,
+    game_progress: &mut GameProgress,
+    battle_log: &mut BattleLog,
+    type_system: &TypeSystem,
+    world_map: &crate::world::WorldMap,
+    run_clock: &crate::run_clock::RunClock,
+    active_enemies: &mut ActiveEnemies,
+    dodge_tracker: &mut DodgeTracker,
+    combat_config: &CombatConfig,
+    melee_bonus_query: &Query<(&Equipped, &MeleePowerBonus)>,
+    defense_bonus_query: &Query<(&Equipped, &DefenseBonus)>,
+    party_monsters: &mut Query<
+        (&mut Health, &mut Strength, &mut Defense, Entity, &Element),
+        (With<PartyMonster>, Without<SelectedMonster>, Without<Enemy>),
+    >,
+    asset_server: &AssetServer,
+    transform: &Transform,
+    player_health: &mut Health,
+    player_stg: &mut Strength,
+    player_def: &mut Defense,
+    player_entity: Entity,
+    player_type: &Element,
+    player_has_turn: bool,
+    player_agility: i32,
+    mut player_active_effects: Option<&mut ActiveEffects>,
+    enemy_health: &mut Health,
+    enemy_stg: &mut Strength,
+    enemy_def: &mut Defense,
+    enemy_entity: Entity,
+    enemy_boss: Option<&Boss>,
+    enemy_type: &Element,
+    enemy_has_turn: bool,
+    summon_pool: Option<&SummonPool>,
+    has_summoned: bool,
+    enemy_agility: i32,
+    mut enemy_active_effects: Option<&mut ActiveEffects>,
+) {
+    // Actions: 0: attack 1: defend 2: elemental
+    // Let the enemy search ahead for its action instead of rolling it.
+    let party_max_hp = party_monsters
+        .iter()
+        .map(|pm| pm.0.max_health as i32)
+        .max()
+        .unwrap_or(0);
+    let enemy_state = enemy_ai_state(
+        player_health,
+        player_stg,
+        player_def,
+        player_type,
+        player_agility,
+        enemy_health,
+        enemy_stg,
+        enemy_def,
+        enemy_type,
+        enemy_agility,
+        game_progress.current_level,
+    );
+    let enemy_action = choose_enemy_action(enemy_state, type_system, party_max_hp).as_code();
+    log_event!(battle_log, LogSeverity::Normal, "{}", you_message);
+
+    if enemy_action == 0 {
+        log_event!(battle_log, LogSeverity::Normal, "Enemy attacks!")
+    } else if enemy_action == 1 {
+        log_event!(battle_log, LogSeverity::Normal, "Enemy defends!")
+    } else if enemy_action == 2 {
+        log_event!(battle_log, LogSeverity::Normal, "Enemy uses an elemental attack!")
+    } else {
+        log_event!(battle_log, LogSeverity::Normal, "Enemy uses its special ability!")
+    }
+
+    // Buffs are read from each side's ActiveEffects instead of the old
+    // `game_progress.turns_left_of_buff` array index.
+    let player_strength_buff = match &player_active_effects {
+        Some(effects) => effects.magnitude_for(StatusEffectKind::StrengthBuff),
+        None => 0,
+    };
+    let player_defense_buff = match &player_active_effects {
+        Some(effects) => effects.magnitude_for(StatusEffectKind::DefenseBuff),
+        None => 0,
+    };
+    let enemy_strength_buff = match &enemy_active_effects {
+        Some(effects) => effects.magnitude_for(StatusEffectKind::StrengthBuff),
+        None => 0,
+    };
+    let enemy_defense_buff = match &enemy_active_effects {
+        Some(effects) => effects.magnitude_for(StatusEffectKind::DefenseBuff),
+        None => 0,
+    };
+    let player_crit_buff = match &player_active_effects {
+        Some(effects) => effects.magnitude_for(StatusEffectKind::CritUp),
+        None => 0,
+    };
+    let enemy_crit_buff = match &enemy_active_effects {
+        Some(effects) => effects.magnitude_for(StatusEffectKind::CritUp),
+        None => 0,
+    };
+
+    // Temporarily fold in equipped gear bonuses and the active buffs,
+    // exactly like the str_buff_damage pattern used to.
+    let (player_melee_bonus, player_defense_bonus) =
+        equipment_bonuses(player_entity, melee_bonus_query, defense_bonus_query);
+    let (enemy_melee_bonus, enemy_defense_bonus) =
+        equipment_bonuses(enemy_entity, melee_bonus_query, defense_bonus_query);
+    player_stg.atk += player_strength_buff + player_melee_bonus;
+    player_def.def += player_defense_buff + player_defense_bonus;
+    enemy_stg.atk += enemy_strength_buff + enemy_melee_bonus;
+    enemy_def.def += enemy_defense_buff + enemy_defense_bonus;
+    player_stg.crt += player_crit_buff;
+    enemy_stg.crt += enemy_crit_buff;
+    let turn_result = calculate_turn(
+        player_stg,
+        player_def,
+        player_type,
+        action_code,
+        player_agility,
+        player_entity,
+        enemy_stg,
+        enemy_def,
+        enemy_type,
+        enemy_action,
+        enemy_agility,
+        enemy_entity,
+        *type_system,
+        game_progress.current_level,
+        dodge_tracker,
+        combat_config.model,
+    );
+    // Reset strength/defense/crit for next turn
+    player_stg.atk -= player_strength_buff + player_melee_bonus;
+    player_def.def -= player_defense_buff + player_defense_bonus;
+    enemy_stg.atk -= enemy_strength_buff + enemy_melee_bonus;
+    enemy_def.def -= enemy_defense_buff + enemy_defense_bonus;
+    player_stg.crt -= player_crit_buff;
+    enemy_stg.crt -= enemy_crit_buff;
+
+    if player_has_turn {
+        enemy_health.health -= turn_result.0;
+    }
+    if let (Some(boss), Some(pool)) = (enemy_boss, summon_pool) {
+        maybe_summon_reinforcements(
+            commands,
+            game_progress,
+            active_enemies,
+            asset_server,
+            enemy_entity,
+            transform,
+            enemy_health,
+            boss,
+            pool,
+            has_summoned,
+        );
+    }
+    // Enemy only lands its half of the exchange once it has actually
+    // accrued enough initiative to act this turn.
+    if enemy_has_turn {
+        player_health.health -= turn_result.1;
+        commands.entity(enemy_entity).remove::<MyTurn>();
+    }
+    if player_has_turn {
+        commands.entity(player_entity).remove::<MyTurn>();
+    }
+
+    // Tick timed effects for both sides: apply poison/regen dots and
+    // count buffs/DoTs down toward expiry. Only tick a side once a turn has
+    // actually resolved for it -- otherwise spamming the input before either
+    // side has accrued initiative would burn down durations (and land
+    // poison damage) on exchanges that never happened.
+    if player_has_turn {
+        if let Some(effects) = &mut player_active_effects {
+            effects.tick(player_health, "You", battle_log);
+        }
+    }
+    if enemy_has_turn {
+        if let Some(effects) = &mut enemy_active_effects {
+            effects.tick(enemy_health, "Enemy", battle_log);
+        }
+    }
+
+    if enemy_health.health <= 0 {
+        log_event!(battle_log, LogSeverity::Reward, "Enemy monster defeated. Your monsters will level up!");
+        // at this point this monster is already "ours", we just need to register is with the resource
+        // get the stats from the monster
+        let mut new_monster_stats = *game_progress.enemy_stats.get(&enemy_entity).unwrap();
+        // Clamp health down so we don't keep boss health
+        new_monster_stats.hp.health = game_progress.current_level as isize * 10;
+        new_monster_stats.hp.max_health = game_progress.current_level * 10;
+        // remove the monster from the enemy stats
+        game_progress.enemy_stats.remove(&enemy_entity);
+        // add the monster to the monster bag
+        commands.entity(enemy_entity).insert(PartyMonster);
+        game_progress.new_monster(enemy_entity, new_monster_stats);
+
+        for pm in party_monsters.iter_mut() {
+            monster_level_up!(commands, game_progress, pm.3, 1);
+        }
+        monster_level_up!(commands, game_progress, player_entity, 1);
+        monster_level_up!(commands, game_progress, enemy_entity, 1);
+
+        // The fight only ends once every tracked enemy is down; a
+        // defeated reinforcement just steps out of the queue and the
+        // next one in line becomes the active target.
+        active_enemies.entities.retain(|&tracked| tracked != enemy_entity);
+
+        if active_enemies.entities.is_empty() {
+            if enemy_boss.is_some() {
+                log_event!(battle_log, LogSeverity::Reward, "Boss defeated!");
+                game_progress.get_quest_rewards(*enemy_type);
+                game_progress.win_boss();
+                crate::save::save_game(game_progress, world_map, run_clock);
+                commands.entity(enemy_entity).remove::<Boss>();
+
+                // Spawn an NPC if enemy_boss is some and we won
+                let new_quest = Quest::random();
+                log_event!(battle_log, LogSeverity::Normal, "Someone appears in the dust!");
+                commands
+                    .spawn_bundle(SpriteBundle {
+                        texture: asset_server.load(NPC_PATH),
+                        transform: Transform::from_xyz(
+                            transform.translation.x,
+                            transform.translation.y,
+                            0.,
+                        ),
+                        ..default()
+                    })
+                    .insert(NPC { quest: new_quest });
+            } else {
+                game_progress.win_battle();
+                game_progress.get_quest_rewards(*enemy_type);
+            }
+            end_battle!(commands, game_progress, player_entity, enemy_entity);
+        } else {
+            log_event!(battle_log, LogSeverity::Normal, "Another enemy steps forward!");
+        }
+    } else if player_health.health <= 0 {
+        game_progress.num_living_monsters -= 1;
+        let next_monster = game_progress.next_monster_cyclic(player_entity);
+        if next_monster.is_none() {
+            log_event!(battle_log, LogSeverity::Faint, "Your monster was defeated.");
+            end_battle!(commands, game_progress, player_entity, enemy_entity);
+        } else {
+            log_event!(battle_log, LogSeverity::Faint, "Your monster was defeated. Switching to next monster.");
+            commands.entity(player_entity).remove::<SelectedMonster>();
+            commands
+                .entity(player_entity)
+                .remove_bundle::<SpriteBundle>();
+            commands.entity(player_entity).remove::<PlayerMonster>();
+            commands.entity(player_entity).remove::<Monster>();
+            commands
+                .entity(*next_monster.unwrap())
+                .insert(SelectedMonster);
+        }
+    }
+}
+
+/// Snapshot the current exchange into the lightweight state `enemy_ai`
+/// searches over, so its forward model doesn't need live ECS queries.
+#[allow(clippy::too_many_arguments)]
+fn enemy_ai_state(
+    player_health: &Health,
+    player_stg: &Strength,
+    player_def: &Defense,
+    player_type: &Element,
+    player_agility: i32,
+    enemy_health: &Health,
+    enemy_stg: &Strength,
+    enemy_def: &Defense,
+    enemy_type: &Element,
+    enemy_agility: i32,
+    level: i32,
+) -> BattleState {
+    BattleState {
+        player_hp: player_health.health as f32,
+        player_atk: player_stg.atk,
+        player_def: player_def.def,
+        player_crt: player_stg.crt,
+        player_crt_res: player_def.crt_res,
+        player_crt_dmg: player_stg.crt_dmg,
+        player_agility,
+        player_type: *player_type,
+        enemy_hp: enemy_health.health as f32,
+        enemy_atk: enemy_stg.atk,
+        enemy_def: enemy_def.def,
+        enemy_crt: enemy_stg.crt,
+        enemy_crt_res: enemy_def.crt_res,
+        enemy_crt_dmg: enemy_stg.crt_dmg,
+        enemy_agility,
+        enemy_type: *enemy_type,
+        level,
+    }
+}
+
+/// Resolve one side's damage under the `CombatModel::Dice` model: roll
+/// `do_challenge` for the attacker's `body + strength` against a d20, and
+/// subtract a quarter of the defender's `body + toughness` from the margin
+/// (standing in for damage mitigation the way `def` does in the arithmetic
+/// model).
+fn resolve_dice_damage(attacker_atk: i32, defender_def: i32) -> usize {
+    let attacker_hit = BASE_BODY.saturating_add(attacker_atk.clamp(0, 245) as u8);
+    let defender_hit = BASE_BODY.saturating_add(defender_def.clamp(0, 245) as u8);
+
+    let (hit, margin) = do_challenge(attacker_hit);
+    if !hit {
+        return 0;
+    }
+
+    margin.saturating_sub(defender_hit / 4) as usize
+}
+
+/// A counter-hit landed by a defending side that fully blocked the
+/// incoming attack. Kept small and flat -- this is a risk/reward nudge,
+/// not a second attack.
+pub(crate) const COUNTER_DAMAGE: usize = 2;
+
+/// Resolve the damage a *defending* side takes from `attacker_atk`, in
+/// place of the normal attack resolution: the usual `atk - def` margin is
+/// halved, then reduced further by a flat block derived from `BASE_BODY`
+/// (standing in for toughness), and can never crit. Returns
+/// `(damage_taken, counter_damage)` -- `counter_damage` is only non-zero
+/// when the block fully absorbed the halved margin (a "full block"),
+/// reflecting a small hit back at the attacker. Also used by
+/// `enemy_ai::expected_defend` to keep the AI's lookahead in sync with the
+/// real guard math.
+pub(crate) fn resolve_defend(attacker_atk: i32, defender_def: i32) -> (usize, usize) {
+    let block = BASE_BODY as i32 / 2;
+    let raw_margin = (attacker_atk - defender_def).max(0);
+    let mitigated = raw_margin / 2;
+
+    if mitigated <= block {
+        (0, COUNTER_DAMAGE)
+    } else {
+        ((mitigated - block) as usize, 0)
+    }
+}
+
 fn calculate_turn(
     player_stg: &Strength,
     player_def: &Defense,
     player_type: &Element,
     player_action: usize,
+    player_agility: i32,
+    player_entity: Entity,
     enemy_stg: &Strength,
     enemy_def: &Defense,
     enemy_type: &Element,
     enemy_action: usize,
+    enemy_agility: i32,
+    enemy_entity: Entity,
     type_system: TypeSystem,
+    level: i32,
+    dodge_tracker: &mut DodgeTracker,
+    combat_model: CombatModel,
 ) -> (isize, isize) {
-    if player_action == 1 || enemy_action == 1 {
-        // if either side defends this turn will not have any damage on either side
-        return (0, 0);
-    }
     // More actions can be added later, we can also consider decoupling the actions from the damage
-    let mut result = (
+    let mut result: (usize, usize) = (
         0, // Your damage to enemy
         0, // Enemy's damage to you
     );
-    // player attacks
-    // If our attack is less than the enemy's defense, we do 0 damage
-    if player_stg.atk <= enemy_def.def {
-        result.0 = 0;
+
+    // Each side gets a chance to dodge the incoming attack before damage is
+    // resolved. A monster's dodge budget for the fight is tracked by
+    // `dodge_tracker`; once exhausted its effective dodge is halved. Only
+    // roll (and spend) a side's dodge when there's actually an attack
+    // coming its way this exchange -- a defending attacker never throws a
+    // hit, so rolling the other side's dodge against it would burn a real
+    // dodge on a phantom attack.
+    let enemy_dodged_player = if player_action != 1 {
+        let enemy_dodge = dodge_value(enemy_agility, level, enemy_stg.atk);
+        roll_dodge(dodge_tracker, enemy_entity, enemy_dodge)
     } else {
-        // if we have damage, we do that much damage
-        // I've only implemented crits for now, dodge and element can follow
-        result.0 = player_stg.atk - enemy_def.def;
-        if player_stg.crt > enemy_def.crt_res {
-            // calculate crit chance and apply crit damage
-            let crit_chance = player_stg.crt - enemy_def.crt_res;
-            let crit = rand::thread_rng().gen_range(0..=100);
-            if crit <= crit_chance {
-                info!("You had a critical strike!");
-                result.0 *= player_stg.crt_dmg;
-            }
+        false
+    };
+    let player_dodged_enemy = if enemy_action != 1 {
+        let player_dodge = dodge_value(player_agility, level, player_stg.atk);
+        roll_dodge(dodge_tracker, player_entity, player_dodge)
+    } else {
+        false
+    };
+
+    // player attacks, unless you're defending this turn -- a defending
+    // side doesn't throw its own attack, though a full block below may
+    // still land a counter-hit in its place.
+    if player_action == 1 {
+        // handled as a possible counter-hit in the enemy's half below
+    } else if enemy_dodged_player {
+        info!("Enemy dodged the attack!");
+    } else if enemy_action == 1 {
+        // Enemy is guarding: mitigated damage, and a reflected counter-hit
+        // back at you if the guard fully blocked it. No crit while guarding.
+        let (damage_taken, counter) = resolve_defend(player_stg.atk, enemy_def.def);
+        result.0 = damage_taken;
+        result.1 += counter;
+        if counter > 0 {
+            info!("Enemy blocks fully and counters!");
         }
-    }
-    // same for enemy
-    if enemy_stg.atk <= player_def.def {
-        result.1 = 0;
     } else {
-        result.1 = enemy_stg.atk - player_def.def;
-        if enemy_stg.crt > player_def.crt_res {
-            let crit_chance = enemy_stg.crt - player_def.crt_res;
-            let crit = rand::thread_rng().gen_range(0..=100);
-            if crit <= crit_chance {
-                info!("Enemy had a critical strike!");
-                result.1 *= enemy_stg.crt_dmg;
+        result.0 = match combat_model {
+            CombatModel::Arithmetic => {
+                // If our attack is less than the enemy's defense, we do 0 damage
+                if player_stg.atk <= enemy_def.def {
+                    0
+                } else {
+                    // if we have damage, we do that much damage
+                    let mut dmg = player_stg.atk - enemy_def.def;
+                    if player_stg.crt > enemy_def.crt_res {
+                        // calculate crit chance and apply crit damage
+                        let crit_chance = player_stg.crt - enemy_def.crt_res;
+                        let crit = rand::thread_rng().gen_range(0..=100);
+                        if crit <= crit_chance {
+                            info!("You had a critical strike!");
+                            dmg *= player_stg.crt_dmg;
+                        }
+                    }
+                    dmg
+                }
             }
+            CombatModel::Dice => resolve_dice_damage(player_stg.atk, enemy_def.def),
+        };
+    }
+    // same for enemy, unless the enemy is defending this turn
+    if enemy_action == 1 {
+        // handled as a possible counter-hit in the player's half above
+    } else if player_dodged_enemy {
+        info!("You dodged the attack!");
+    } else if player_action == 1 {
+        let (damage_taken, counter) = resolve_defend(enemy_stg.atk, player_def.def);
+        result.1 = damage_taken;
+        result.0 += counter;
+        if counter > 0 {
+            info!("You block fully and counter!");
         }
+    } else {
+        result.1 = match combat_model {
+            CombatModel::Arithmetic => {
+                if enemy_stg.atk <= player_def.def {
+                    0
+                } else {
+                    let mut dmg = enemy_stg.atk - player_def.def;
+                    if enemy_stg.crt > player_def.crt_res {
+                        let crit_chance = enemy_stg.crt - player_def.crt_res;
+                        let crit = rand::thread_rng().gen_range(0..=100);
+                        if crit <= crit_chance {
+                            info!("Enemy had a critical strike!");
+                            dmg *= enemy_stg.crt_dmg;
+                        }
+                    }
+                    dmg
+                }
+            }
+            CombatModel::Dice => resolve_dice_damage(enemy_stg.atk, player_def.def),
+        };
     }
 
     if player_action == 2 {
@@ -905,3 +1529,89 @@ fn calculate_turn(
 
     (result.0 as isize, result.1 as isize)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Give a side a dodge value of 0 (dodge_value floors at 0, and a roll
+    /// is drawn from 1..=100) so `roll_dodge` can never evade in a test,
+    /// without having to stub out the RNG.
+    const ZERO_DODGE_ATK: i32 = 100;
+
+    fn turn(
+        player_stg: Strength,
+        player_def: Defense,
+        player_action: usize,
+        enemy_stg: Strength,
+        enemy_def: Defense,
+        enemy_action: usize,
+    ) -> (isize, isize) {
+        let mut dodge_tracker = DodgeTracker::default();
+        calculate_turn(
+            &player_stg,
+            &player_def,
+            &Element::Normal,
+            player_action,
+            0,
+            Entity::from_raw(0),
+            &enemy_stg,
+            &enemy_def,
+            &Element::Normal,
+            enemy_action,
+            0,
+            Entity::from_raw(1),
+            TypeSystem::default(),
+            0,
+            &mut dodge_tracker,
+            CombatModel::Arithmetic,
+        )
+    }
+
+    #[test]
+    fn defend_vs_attack_mitigates_and_does_not_counter_above_the_block_threshold() {
+        // attacker atk 30 vs defender def 0: raw_margin 30, mitigated 15,
+        // which clears the BASE_BODY/2 == 5 block, so it lands for the
+        // leftover 10 and no counter-hit fires.
+        let result = turn(
+            Strength { atk: 30, ..Default::default() },
+            Defense::default(),
+            0,
+            Strength { atk: ZERO_DODGE_ATK, ..Default::default() },
+            Defense::default(),
+            1,
+        );
+        assert_eq!(result, (10, 0));
+    }
+
+    #[test]
+    fn defend_vs_attack_fully_blocks_and_counters_at_the_threshold() {
+        // attacker atk 12 vs defender def 5: raw_margin 7, mitigated 3,
+        // which sits at/under the BASE_BODY/2 == 5 block, so it's a full
+        // block and the defender fires back a COUNTER_DAMAGE counter-hit.
+        let result = turn(
+            Strength { atk: 12, ..Default::default() },
+            Defense::default(),
+            0,
+            Strength { atk: ZERO_DODGE_ATK, ..Default::default() },
+            Defense { def: 5, ..Default::default() },
+            1,
+        );
+        assert_eq!(result, (0, COUNTER_DAMAGE as isize));
+    }
+
+    #[test]
+    fn defend_vs_defend_is_a_stalemate() {
+        // Neither side throws an attack, so neither lands damage or a
+        // counter-hit; this used to be implicit, worth asserting directly.
+        let result = turn(
+            Strength { atk: 50, ..Default::default() },
+            Defense::default(),
+            1,
+            Strength { atk: 50, ..Default::default() },
+            Defense::default(),
+            1,
+        );
+        assert_eq!(result, (0, 0));
+    }
+}
@@ -0,0 +1,102 @@
+use crate::battle::{build_enemy_stats, EnemySpriteReady};
+use crate::initiative::Speed;
+use crate::monster::{get_monster_sprite_for_type, Boss, Element, Enemy, Health};
+use crate::run_clock::scale_stats_for_difficulty;
+use crate::status_effects::ActiveEffects;
+use crate::world::GameProgress;
+use bevy::prelude::*;
+use rand::*;
+
+/// Marks that a boss has already spawned its reinforcements for this fight,
+/// so `summon_threshold` can only trigger once.
+#[derive(Component)]
+pub(crate) struct HasSummoned;
+
+/// Optional summon configuration carried on a `Boss`. A boss without one
+/// never calls for reinforcements.
+#[derive(Clone)]
+pub(crate) struct SummonPool {
+    pub(crate) elements: Vec<Element>,
+    /// Fraction of max health (0.0-1.0) at which the boss summons help.
+    pub(crate) summon_threshold: f32,
+}
+
+/// Tracks every enemy entity currently alive in the fight, so the battle
+/// only ends once all of them are defeated.
+#[derive(Default)]
+pub(crate) struct ActiveEnemies {
+    pub(crate) entities: Vec<Entity>,
+}
+
+/// Check whether a boss has just crossed its summon threshold and, if so,
+/// spawn 1-2 reinforcements positioned offset from the boss.
+pub(crate) fn maybe_summon_reinforcements(
+    commands: &mut Commands,
+    game_progress: &mut GameProgress,
+    active_enemies: &mut ActiveEnemies,
+    asset_server: &AssetServer,
+    boss_entity: Entity,
+    boss_transform: &Transform,
+    boss_health: &Health,
+    boss: &Boss,
+    summon_pool: &SummonPool,
+    already_summoned: bool,
+) {
+    if already_summoned {
+        return;
+    }
+
+    let health_fraction = boss_health.health as f32 / boss_health.max_health as f32;
+    if health_fraction > summon_pool.summon_threshold {
+        return;
+    }
+
+    if summon_pool.elements.is_empty() {
+        return;
+    }
+
+    commands.entity(boss_entity).insert(HasSummoned);
+
+    let num_to_summon = rand::thread_rng().gen_range(1..=2);
+    for i in 0..num_to_summon {
+        let element = summon_pool.elements[rand::thread_rng().gen_range(0..summon_pool.elements.len())];
+        // `boss_transform` is the battle camera's transform; the boss itself
+        // stands 400px right of it (see `spawn_enemy_monster`).
+        let offset_x = 400. + 80.0 * (i as f32 + 1.0);
+
+        // Build through the same atk/def/crit curve a freshly rolled enemy
+        // gets, scaled by the same difficulty multiplier, so reinforcements
+        // don't trail behind the boss that summoned them.
+        let mut stats = build_enemy_stats(element, game_progress.current_level as i32);
+        scale_stats_for_difficulty(&mut stats, game_progress.difficulty_multiplier);
+        // HP is tied to the boss's own health rather than the level curve --
+        // reinforcements are meant to be disposable adds, not boss-strength
+        // monsters in their own right -- but still difficulty-scaled like
+        // everything else above.
+        stats.hp.max_health =
+            (boss_health.max_health as f32 / 4.0 * game_progress.difficulty_multiplier) as u32;
+        stats.hp.health = stats.hp.max_health as isize;
+
+        let reinforcement = commands
+            .spawn_bundle(SpriteBundle {
+                texture: asset_server.load(&get_monster_sprite_for_type(element)),
+                transform: Transform::from_xyz(
+                    boss_transform.translation.x + offset_x,
+                    boss_transform.translation.y - 100.,
+                    1.,
+                ),
+                ..default()
+            })
+            .insert(Enemy)
+            .insert(element)
+            .insert_bundle(stats.clone())
+            .insert(Speed::for_element(element))
+            .insert(ActiveEffects::default())
+            // Already sprited above, so `spawn_enemy_monster` leaves it alone.
+            .insert(EnemySpriteReady)
+            .id();
+
+        active_enemies.entities.push(reinforcement);
+        game_progress.enemy_stats.insert(reinforcement, stats);
+    }
+}
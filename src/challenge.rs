@@ -0,0 +1,83 @@
+use rand::*;
+
+/// A flat "body" term added to a combatant's relevant stat before a
+/// challenge roll, standing in for the Dungeon-Slayer-style body/toughness
+/// attribute this tree doesn't otherwise track per monster.
+pub(crate) const BASE_BODY: u8 = 10;
+
+/// Selects which hit-resolution model `calculate_turn` uses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CombatModel {
+    /// The original `atk - def` arithmetic model.
+    Arithmetic,
+    /// The d20 challenge-roll model implemented by `do_challenge`.
+    Dice,
+}
+
+/// Resource selecting the active hit-resolution model. Defaults to the
+/// original arithmetic model so the dice model is strictly opt-in.
+pub(crate) struct CombatConfig {
+    pub(crate) model: CombatModel,
+}
+
+impl Default for CombatConfig {
+    fn default() -> Self {
+        CombatConfig {
+            model: CombatModel::Arithmetic,
+        }
+    }
+}
+
+/// Roll a d20 challenge against `stat`. A natural 20 is an automatic
+/// failure and a natural 1 is an automatic success; otherwise the roll
+/// succeeds when `roll <= stat`. Returns `(success, margin)`, where margin
+/// is `stat - roll` (saturating at 0) so a barely-passed roll chips for
+/// little and a large margin hits hard.
+pub(crate) fn do_challenge(stat: u8) -> (bool, u8) {
+    resolve_challenge(rand::thread_rng().gen_range(1..=20u8), stat)
+}
+
+/// The deterministic half of `do_challenge`, split out so the edge cases
+/// can be tested without depending on the RNG.
+fn resolve_challenge(roll: u8, stat: u8) -> (bool, u8) {
+    let success = match roll {
+        20 => false,
+        1 => true,
+        _ => roll <= stat,
+    };
+
+    (success, stat.saturating_sub(roll))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn natural_twenty_always_fails() {
+        // A stat of 255 would always succeed under `roll <= stat`, so this
+        // only passes if the nat-20 auto-fail rule is actually applied.
+        let (success, _) = resolve_challenge(20, 255);
+        assert!(!success);
+    }
+
+    #[test]
+    fn natural_one_always_succeeds() {
+        let (success, _) = resolve_challenge(1, 0);
+        assert!(success);
+    }
+
+    #[test]
+    fn margin_is_stat_minus_roll_on_success() {
+        let (success, margin) = resolve_challenge(9, 15);
+        assert!(success);
+        assert_eq!(margin, 6);
+    }
+
+    #[test]
+    fn margin_saturates_at_zero_on_failed_roll() {
+        let (success, margin) = resolve_challenge(18, 5);
+        assert!(!success);
+        assert_eq!(margin, 0);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::assets::AssetLoader;
+use bevy::diagnostic::{
+    Diagnostics, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin, SystemInformationDiagnosticsPlugin,
+};
+use bevy::prelude::*;
+
+/// Whether the F3 debug HUD is currently shown. Toggled, not tied to any
+/// `GameState`, so it works in every screen.
+#[derive(Default)]
+pub(crate) struct DebugOverlayVisible(pub(crate) bool);
+
+#[derive(Component)]
+pub(crate) struct DebugOverlayText;
+
+pub(crate) struct DebugOverlayPlugin;
+
+impl Plugin for DebugOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(FrameTimeDiagnosticsPlugin::default())
+            .add_plugin(EntityCountDiagnosticsPlugin::default())
+            .add_plugin(SystemInformationDiagnosticsPlugin::default())
+            .init_resource::<DebugOverlayVisible>()
+            .add_system(toggle_debug_overlay)
+            .add_system(update_debug_overlay);
+    }
+}
+
+fn toggle_debug_overlay(
+    mut commands: Commands,
+    input: Res<Input<KeyCode>>,
+    mut visible: ResMut<DebugOverlayVisible>,
+    asset_loader: Res<AssetLoader>,
+    existing_query: Query<Entity, With<DebugOverlayText>>,
+) {
+    if !input.just_pressed(KeyCode::F3) {
+        return;
+    }
+
+    visible.0 = !visible.0;
+
+    if visible.0 {
+        commands
+            .spawn_bundle(TextBundle::from_section(
+                "",
+                TextStyle {
+                    font: asset_loader.fonts.joystix.clone(),
+                    font_size: 20.0,
+                    color: Color::GREEN,
+                },
+            ))
+            .insert(Style {
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    top: Val::Px(5.0),
+                    right: Val::Px(5.0),
+                    ..default()
+                },
+                ..default()
+            })
+            .insert(DebugOverlayText);
+    } else {
+        existing_query.for_each(|text| {
+            commands.entity(text).despawn();
+        });
+    }
+}
+
+/// Format the FPS/frame-time/process diagnostics into the overlay text, but
+/// only while it's visible, since `expand_map` and `wfc` can be costly and
+/// this is where stutter would show up.
+fn update_debug_overlay(
+    visible: Res<DebugOverlayVisible>,
+    diagnostics: Res<Diagnostics>,
+    mut text_query: Query<&mut Text, With<DebugOverlayText>>,
+) {
+    if !visible.0 {
+        return;
+    }
+
+    let fps = diagnostics
+        .get(FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(|d| d.smoothed())
+        .unwrap_or(0.0);
+    let frame_time = diagnostics
+        .get(FrameTimeDiagnosticsPlugin::FRAME_TIME)
+        .and_then(|d| d.smoothed())
+        .unwrap_or(0.0);
+    let cpu = diagnostics
+        .get(SystemInformationDiagnosticsPlugin::CPU_USAGE)
+        .and_then(|d| d.smoothed())
+        .unwrap_or(0.0);
+    let mem = diagnostics
+        .get(SystemInformationDiagnosticsPlugin::MEM_USAGE)
+        .and_then(|d| d.smoothed())
+        .unwrap_or(0.0);
+
+    for mut text in &mut text_query {
+        text.sections[0].value = format!(
+            "FPS: {:.0}\nframe: {:.2}ms\ncpu: {:.1}%\nmem: {:.1}%",
+            fps,
+            frame_time * 1000.0,
+            cpu,
+            mem
+        );
+    }
+}
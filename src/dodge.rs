@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use rand::*;
+
+const HIT_ROLL_CAP: i32 = 100;
+const STARTING_DODGES_PER_FIGHT: i32 = 3;
+
+/// Per-combatant dodge budget for the current encounter. A monster cannot
+/// dodge indefinitely across a long fight -- once its dodges are exhausted,
+/// its effective dodge is averaged down toward zero instead of being reset.
+#[derive(Default)]
+pub(crate) struct DodgeTracker {
+    dodges_left: HashMap<Entity, i32>,
+}
+
+impl DodgeTracker {
+    pub(crate) fn clear(&mut self) {
+        self.dodges_left.clear();
+    }
+
+    fn dodges_left_for(&mut self, entity: Entity) -> i32 {
+        *self
+            .dodges_left
+            .entry(entity)
+            .or_insert(STARTING_DODGES_PER_FIGHT)
+    }
+
+    fn spend_dodge(&mut self, entity: Entity) {
+        if let Some(left) = self.dodges_left.get_mut(&entity) {
+            *left -= 1;
+        }
+    }
+}
+
+/// `dodge = base + agility/2 + level_bonus`, in the spirit of the Cataclysm
+/// formula, with a penalty for very high raw strength (huge/slow monsters
+/// are easier to hit) and a bonus for low raw strength.
+pub(crate) fn dodge_value(agility: i32, level: i32, raw_strength: i32) -> i32 {
+    let base = 10;
+    let level_bonus = level / 2;
+    let mut dodge = base + agility / 2 + level_bonus;
+
+    if raw_strength > 50 {
+        dodge -= (raw_strength - 50) / 5;
+    } else if raw_strength < 20 {
+        dodge += (20 - raw_strength) / 4;
+    }
+
+    dodge.max(0)
+}
+
+/// Roll to see whether `defender` evades an incoming attack. Once a
+/// combatant has spent all of its dodges for the fight, its effective dodge
+/// value is halved (averaged down toward zero) rather than reset.
+pub(crate) fn roll_dodge(tracker: &mut DodgeTracker, defender: Entity, dodge: i32) -> bool {
+    let dodges_left = tracker.dodges_left_for(defender);
+    let effective_dodge = if dodges_left > 0 { dodge } else { dodge / 2 };
+
+    let roll = rand::thread_rng().gen_range(1..=HIT_ROLL_CAP);
+    let evaded = roll <= effective_dodge;
+
+    if evaded {
+        tracker.spend_dodge(defender);
+    }
+
+    evaded
+}
@@ -0,0 +1,108 @@
+use crate::monster::Element;
+use bevy::prelude::*;
+use rand::*;
+
+/// Cumulative-weight selection over a list of `(entry, weight)` pairs.
+#[derive(Default, Clone)]
+pub(crate) struct RandomTable<T: Clone> {
+    entries: Vec<(T, i32)>,
+}
+
+impl<T: Clone> RandomTable<T> {
+    pub(crate) fn new() -> Self {
+        RandomTable { entries: Vec::new() }
+    }
+
+    pub(crate) fn add(mut self, entry: T, weight: i32) -> Self {
+        self.entries.push((entry, weight));
+        self
+    }
+
+    /// Draw an entry, weighted by each entry's share of the total weight.
+    /// Returns `None` for an empty table.
+    pub(crate) fn roll(&self, rng: &mut impl Rng) -> Option<&T> {
+        let total_weight: i32 = self.entries.iter().map(|(_, weight)| weight).sum();
+        if total_weight <= 0 {
+            return None;
+        }
+
+        let mut picked = rng.gen_range(0..total_weight);
+        for (entry, weight) in &self.entries {
+            if picked < *weight {
+                return Some(entry);
+            }
+            picked -= weight;
+        }
+        None
+    }
+}
+
+/// One entry in an encounter table: the element to spawn and whether it's
+/// a boss encounter.
+#[derive(Clone, Copy)]
+pub(crate) struct EncounterEntry {
+    pub(crate) element: Element,
+    pub(crate) is_boss: bool,
+}
+
+/// Per-depth encounter tables, keyed on `GameProgress.current_level`, so
+/// tuning doesn't require touching spawn code.
+pub(crate) struct EncounterTables {
+    tables: Vec<(u32, RandomTable<EncounterEntry>)>,
+}
+
+impl Default for EncounterTables {
+    fn default() -> Self {
+        let entry = |element, is_boss| EncounterEntry { element, is_boss };
+
+        EncounterTables {
+            tables: vec![
+                (
+                    0,
+                    RandomTable::new()
+                        .add(entry(Element::Normal, false), 60)
+                        .add(entry(Element::Fire, false), 20)
+                        .add(entry(Element::Water, false), 20),
+                ),
+                (
+                    5,
+                    RandomTable::new()
+                        .add(entry(Element::Normal, false), 30)
+                        .add(entry(Element::Fire, false), 25)
+                        .add(entry(Element::Water, false), 25)
+                        .add(entry(Element::Earth, false), 18)
+                        .add(entry(Element::Fire, true), 2),
+                ),
+                (
+                    10,
+                    RandomTable::new()
+                        .add(entry(Element::Fire, false), 22)
+                        .add(entry(Element::Water, false), 22)
+                        .add(entry(Element::Earth, false), 22)
+                        .add(entry(Element::Air, false), 22)
+                        .add(entry(Element::Water, true), 12),
+                ),
+            ],
+        }
+    }
+}
+
+impl EncounterTables {
+    /// The table for the highest depth threshold at or below `current_level`.
+    pub(crate) fn table_for_level(&self, current_level: u32) -> &RandomTable<EncounterEntry> {
+        self.tables
+            .iter()
+            .rev()
+            .find(|(threshold, _)| current_level >= *threshold)
+            .map(|(_, table)| table)
+            .unwrap_or(&self.tables[0].1)
+    }
+
+    pub(crate) fn roll_for_level(
+        &self,
+        current_level: u32,
+        rng: &mut impl Rng,
+    ) -> Option<EncounterEntry> {
+        self.table_for_level(current_level).roll(rng).copied()
+    }
+}
@@ -0,0 +1,263 @@
+use crate::battle::resolve_defend;
+use crate::dodge::dodge_value;
+use crate::monster::Element;
+use crate::world::TypeSystem;
+
+/// Enemy actions the AI searches over. `Special` plays out identically to
+/// `Attack` in the current damage model, so it isn't a distinct branch.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum EnemyAction {
+    Attack,
+    Defend,
+    Elemental,
+}
+
+const ACTIONS: [EnemyAction; 3] = [
+    EnemyAction::Attack,
+    EnemyAction::Defend,
+    EnemyAction::Elemental,
+];
+
+impl EnemyAction {
+    /// The action code `calculate_turn` expects: 0 attack, 1 defend, 2 elemental.
+    pub(crate) fn as_code(self) -> usize {
+        match self {
+            EnemyAction::Attack => 0,
+            EnemyAction::Defend => 1,
+            EnemyAction::Elemental => 2,
+        }
+    }
+}
+
+/// A snapshot of everything the forward model needs, independent of any
+/// live ECS query so it's cheap to clone and mutate while searching.
+/// `calculate_turn` itself rolls real RNG and mutates `DodgeTracker`, so
+/// it isn't suited to a lookahead tree; this mirrors its arithmetic with
+/// expected values instead of dice rolls.
+#[derive(Clone, Copy)]
+pub(crate) struct BattleState {
+    pub(crate) player_hp: f32,
+    pub(crate) player_atk: i32,
+    pub(crate) player_def: i32,
+    pub(crate) player_crt: i32,
+    pub(crate) player_crt_res: i32,
+    pub(crate) player_crt_dmg: i32,
+    pub(crate) player_agility: i32,
+    pub(crate) player_type: Element,
+    pub(crate) enemy_hp: f32,
+    pub(crate) enemy_atk: i32,
+    pub(crate) enemy_def: i32,
+    pub(crate) enemy_crt: i32,
+    pub(crate) enemy_crt_res: i32,
+    pub(crate) enemy_crt_dmg: i32,
+    pub(crate) enemy_agility: i32,
+    pub(crate) enemy_type: Element,
+    pub(crate) level: i32,
+}
+
+/// Expected damage for one side's attack, in the spirit of `calculate_turn`
+/// but averaging over crit chance and dodge chance instead of rolling them.
+fn expected_damage(
+    attacker_atk: i32,
+    attacker_crt: i32,
+    attacker_crt_dmg: i32,
+    defender_def: i32,
+    defender_crt_res: i32,
+    defender_atk: i32,
+    defender_agility: i32,
+    level: i32,
+) -> f32 {
+    if attacker_atk <= defender_def {
+        return 0.0;
+    }
+    let base = (attacker_atk - defender_def) as f32;
+
+    let crit_chance = (attacker_crt - defender_crt_res).clamp(0, 100) as f32 / 100.0;
+    let crit_expectation = 1.0 + crit_chance * (attacker_crt_dmg as f32 - 1.0);
+
+    let dodge_chance = dodge_value(defender_agility, level, defender_atk).max(0) as f32 / 100.0;
+
+    base * crit_expectation * (1.0 - dodge_chance.min(1.0))
+}
+
+/// Expected value of `resolve_defend`'s block/counter split, folding in the
+/// chance the attack is dodged outright before it ever reaches the guard --
+/// `calculate_turn` still rolls the defender's dodge while it's guarding, and
+/// a dodge bypasses the guard (and its counter-hit) entirely. Returns
+/// `(expected_damage_taken, expected_counter_damage)`.
+fn expected_defend(
+    attacker_atk: i32,
+    defender_def: i32,
+    defender_atk: i32,
+    defender_agility: i32,
+    level: i32,
+) -> (f32, f32) {
+    let dodge_chance = dodge_value(defender_agility, level, defender_atk).max(0) as f32 / 100.0;
+    let not_dodged = 1.0 - dodge_chance.min(1.0);
+
+    let (damage_taken, counter) = resolve_defend(attacker_atk, defender_def);
+
+    (not_dodged * damage_taken as f32, not_dodged * counter as f32)
+}
+
+fn apply_type_multiplier(
+    damage: f32,
+    action: EnemyAction,
+    attacker_type: Element,
+    defender_type: Element,
+    type_system: &TypeSystem,
+) -> f32 {
+    if action == EnemyAction::Elemental {
+        damage * type_system.type_modifier[attacker_type as usize][defender_type as usize]
+    } else {
+        damage
+    }
+}
+
+/// One ply of combat: damage each side deals to the other given both
+/// actions for that exchange. Mirrors `calculate_turn`/`resolve_defend`'s
+/// partial-mitigation-plus-counter guard, in expectation rather than as a
+/// flat "either side defends -> no damage at all" rule -- a defending side
+/// still takes some damage (and may land a counter-hit) unless its guard
+/// fully absorbs the blow.
+fn simulate_exchange(
+    state: &BattleState,
+    enemy_action: EnemyAction,
+    player_action: EnemyAction,
+    type_system: &TypeSystem,
+) -> (f32, f32) {
+    let mut dmg_to_enemy = 0.0;
+    let mut dmg_to_player = 0.0;
+
+    if player_action != EnemyAction::Defend {
+        if enemy_action == EnemyAction::Defend {
+            let (damage_taken, counter) =
+                expected_defend(state.player_atk, state.enemy_def, state.enemy_atk, state.enemy_agility, state.level);
+            dmg_to_enemy += damage_taken;
+            dmg_to_player += counter;
+        } else {
+            dmg_to_enemy += expected_damage(
+                state.player_atk,
+                state.player_crt,
+                state.player_crt_dmg,
+                state.enemy_def,
+                state.enemy_crt_res,
+                state.enemy_atk,
+                state.enemy_agility,
+                state.level,
+            );
+        }
+        dmg_to_enemy = apply_type_multiplier(dmg_to_enemy, player_action, state.player_type, state.enemy_type, type_system);
+    }
+
+    if enemy_action != EnemyAction::Defend {
+        if player_action == EnemyAction::Defend {
+            let (damage_taken, counter) =
+                expected_defend(state.enemy_atk, state.player_def, state.player_atk, state.player_agility, state.level);
+            dmg_to_player += damage_taken;
+            dmg_to_enemy += counter;
+        } else {
+            dmg_to_player += expected_damage(
+                state.enemy_atk,
+                state.enemy_crt,
+                state.enemy_crt_dmg,
+                state.player_def,
+                state.player_crt_res,
+                state.player_atk,
+                state.player_agility,
+                state.level,
+            );
+        }
+        dmg_to_player = apply_type_multiplier(dmg_to_player, enemy_action, state.enemy_type, state.player_type, type_system);
+    }
+
+    (dmg_to_player, dmg_to_enemy)
+}
+
+/// Multi-criteria heuristic: own HP remaining and damage dealt (folded into
+/// the HP differential) and whether a kill is secured either way. The party
+/// term subtracts a fixed share of `party_max_hp` (the highest max health
+/// among party monsters not currently fighting); it is the same for every
+/// state in one search, so it never changes which action is chosen.
+fn score_state(state: &BattleState, party_max_hp: i32) -> f32 {
+    let mut score = state.enemy_hp - state.player_hp;
+
+    if state.player_hp <= 0.0 {
+        score += 1000.0;
+    }
+    if state.enemy_hp <= 0.0 {
+        score -= 1000.0;
+    }
+
+    score - party_max_hp as f32 * 0.01
+}
+
+/// Search depth caps with the current game level
+/// (`GameProgress::current_level`), so early fights stay
+/// shallow (and beatable) and only later levels get the full 3-ply search.
+fn depth_for_level(level: i32) -> u32 {
+    match level {
+        l if l < 3 => 1,
+        l if l < 6 => 2,
+        _ => 3,
+    }
+}
+
+/// Player layer minimizes the enemy's advantage, enemy layer maximizes it,
+/// alternating for `depth` plies. Randomness is folded in as an expectation
+/// rather than literal chance nodes (see `expected_damage`).
+fn expectimax(
+    state: BattleState,
+    enemy_action: EnemyAction,
+    type_system: &TypeSystem,
+    party_max_hp: i32,
+    depth: u32,
+) -> f32 {
+    let mut worst_for_enemy = f32::INFINITY;
+
+    for &player_action in ACTIONS.iter() {
+        let (dmg_to_player, dmg_to_enemy) =
+            simulate_exchange(&state, enemy_action, player_action, type_system);
+
+        let mut next_state = state;
+        next_state.player_hp = (state.player_hp - dmg_to_player).max(0.0);
+        next_state.enemy_hp = (state.enemy_hp - dmg_to_enemy).max(0.0);
+
+        let value = if depth <= 1 || next_state.player_hp <= 0.0 || next_state.enemy_hp <= 0.0 {
+            score_state(&next_state, party_max_hp)
+        } else {
+            ACTIONS
+                .iter()
+                .map(|&next_enemy_action| {
+                    expectimax(next_state, next_enemy_action, type_system, party_max_hp, depth - 1)
+                })
+                .fold(f32::NEG_INFINITY, f32::max)
+        };
+
+        worst_for_enemy = worst_for_enemy.min(value);
+    }
+
+    worst_for_enemy
+}
+
+/// Choose the enemy's action for this turn by searching `depth_for_level`
+/// plies ahead and picking whichever action maximizes the enemy's
+/// worst-case (player-optimal-reply) outcome.
+pub(crate) fn choose_enemy_action(
+    state: BattleState,
+    type_system: &TypeSystem,
+    party_max_hp: i32,
+) -> EnemyAction {
+    let depth = depth_for_level(state.level);
+
+    let mut best_action = EnemyAction::Attack;
+    let mut best_score = f32::NEG_INFINITY;
+    for &enemy_action in ACTIONS.iter() {
+        let score = expectimax(state, enemy_action, type_system, party_max_hp, depth);
+        if score > best_score {
+            best_score = score;
+            best_action = enemy_action;
+        }
+    }
+    best_action
+}
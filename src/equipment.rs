@@ -0,0 +1,60 @@
+use crate::monster::Element;
+use bevy::prelude::*;
+
+/// Which gear slot an item occupies on a monster.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EquipmentSlot {
+    Weapon,
+    Armor,
+    Charm,
+}
+
+/// Marks an entity as a piece of gear that can be equipped into a slot.
+#[derive(Component)]
+pub(crate) struct Equippable {
+    pub(crate) slot: EquipmentSlot,
+}
+
+/// Flat bonus to melee attack power while equipped.
+#[derive(Component)]
+pub(crate) struct MeleePowerBonus {
+    pub(crate) power: i32,
+}
+
+/// Flat bonus to defense while equipped.
+#[derive(Component)]
+pub(crate) struct DefenseBonus {
+    pub(crate) def: i32,
+}
+
+/// Grants (or reinforces) an elemental affinity while equipped.
+#[derive(Component)]
+pub(crate) struct ElementAffinity {
+    pub(crate) element: Element,
+}
+
+/// Links a piece of gear to the monster it is currently equipped on.
+#[derive(Component)]
+pub(crate) struct Equipped {
+    pub(crate) owner: Entity,
+    pub(crate) slot: EquipmentSlot,
+}
+
+/// Sum the melee/defense bonuses of every item `Equipped` to `owner`.
+pub(crate) fn equipment_bonuses(
+    owner: Entity,
+    melee_query: &Query<(&Equipped, &MeleePowerBonus)>,
+    defense_query: &Query<(&Equipped, &DefenseBonus)>,
+) -> (i32, i32) {
+    let melee_bonus: i32 = melee_query
+        .iter()
+        .filter(|(equipped, _)| equipped.owner == owner)
+        .map(|(_, bonus)| bonus.power)
+        .sum();
+    let defense_bonus: i32 = defense_query
+        .iter()
+        .filter(|(equipped, _)| equipped.owner == owner)
+        .map(|(_, bonus)| bonus.def)
+        .sum();
+    (melee_bonus, defense_bonus)
+}
@@ -0,0 +1,108 @@
+use crate::assets::AssetLoader;
+use crate::camera::MenuCamera;
+use crate::monster::{Health, PartyMonster};
+use crate::GameState;
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+
+pub(crate) struct GameOverPlugin;
+
+#[derive(Component)]
+pub(crate) struct GameOverUIElement;
+
+impl Plugin for GameOverPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_enter_system(GameState::GameOver, setup_game_over)
+            .add_system_set(
+                ConditionSet::new()
+                    .run_in_state(GameState::GameOver)
+                    .with_system(handle_game_over_input)
+                    .into(),
+            )
+            .add_exit_system(GameState::GameOver, despawn_game_over)
+            .add_exit_system(GameState::GameOver, crate::teardown);
+    }
+}
+
+/// Mark that the player's party has been wiped out and transition to the
+/// defeat screen. Mirrors `win_game`.
+pub(crate) fn lose_game(
+    mut commands: Commands,
+    party_monsters: Query<&Health, With<PartyMonster>>,
+) {
+    if party_monsters.is_empty() {
+        return;
+    }
+
+    if party_monsters.iter().all(|health| health.health <= 0) {
+        commands.insert_resource(NextState(GameState::GameOver));
+    }
+}
+
+pub(crate) fn setup_game_over(mut commands: Commands, asset_loader: Res<AssetLoader>) {
+    commands.spawn_bundle(Camera2dBundle::default()).insert(MenuCamera);
+
+    commands
+        .spawn_bundle(TextBundle::from_section(
+            "GAME OVER",
+            TextStyle {
+                font: asset_loader.fonts.joystix.clone(),
+                font_size: 60.0,
+                color: Color::WHITE,
+            },
+        ))
+        .insert(Style {
+            align_self: AlignSelf::Center,
+            position_type: PositionType::Absolute,
+            position: UiRect {
+                top: Val::Px(250.0),
+                left: Val::Px(450.0),
+                ..default()
+            },
+            ..default()
+        })
+        .insert(GameOverUIElement);
+
+    commands
+        .spawn_bundle(TextBundle::from_section(
+            "R to restart, Q to quit",
+            TextStyle {
+                font: asset_loader.fonts.joystix.clone(),
+                font_size: 30.0,
+                color: Color::WHITE,
+            },
+        ))
+        .insert(Style {
+            align_self: AlignSelf::Center,
+            position_type: PositionType::Absolute,
+            position: UiRect {
+                top: Val::Px(350.0),
+                left: Val::Px(450.0),
+                ..default()
+            },
+            ..default()
+        })
+        .insert(GameOverUIElement);
+}
+
+pub(crate) fn despawn_game_over(
+    mut commands: Commands,
+    camera_query: Query<Entity, With<MenuCamera>>,
+    ui_query: Query<Entity, With<GameOverUIElement>>,
+) {
+    camera_query.for_each(|camera| {
+        commands.entity(camera).despawn();
+    });
+
+    ui_query.for_each(|element| {
+        commands.entity(element).despawn();
+    });
+}
+
+fn handle_game_over_input(mut commands: Commands, input: Res<Input<KeyCode>>) {
+    if input.just_pressed(KeyCode::R) {
+        commands.insert_resource(NextState(GameState::Start));
+    } else if input.just_pressed(KeyCode::Q) {
+        std::process::exit(0);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::assets::AssetLoader;
 use crate::backgrounds::Tile;
 use crate::camera::HelpCamera;
 use crate::player::Player;
@@ -42,6 +43,7 @@ pub(crate) fn setup_help(
         ),
     >,
     asset_server: Res<AssetServer>,
+    asset_loader: Res<AssetLoader>,
 ) {
     // Despawn all non-help cameras
     cameras.for_each(|camera| {
@@ -64,7 +66,7 @@ pub(crate) fn setup_help(
         .spawn_bundle(TextBundle::from_section(
             "HELP",
             TextStyle {
-                font: asset_server.load("buttons/joystix monospace.ttf"),
+                font: asset_loader.fonts.joystix.clone(),
                 font_size: 40.0,
                 color: Color::WHITE,
             },
@@ -84,7 +86,7 @@ pub(crate) fn setup_help(
         .spawn_bundle(TextBundle::from_section(
             "MOVEMENT CONTROLS",
             TextStyle {
-                font: asset_server.load("buttons/joystix monospace.ttf"),
+                font: asset_loader.fonts.joystix.clone(),
                 font_size: 35.0,
                 color: Color::WHITE,
             },
@@ -104,7 +106,7 @@ pub(crate) fn setup_help(
         .spawn_bundle(TextBundle::from_section(
             "W to move up, S to move down, A to move left, D to move right",
             TextStyle {
-                font: asset_server.load("buttons/joystix monospace.ttf"),
+                font: asset_loader.fonts.joystix.clone(),
                 font_size: 30.0,
                 color: Color::WHITE,
             },
@@ -124,7 +126,7 @@ pub(crate) fn setup_help(
         .spawn_bundle(TextBundle::from_section(
             "BATTLE CONTROLS",
             TextStyle {
-                font: asset_server.load("buttons/joystix monospace.ttf"),
+                font: asset_loader.fonts.joystix.clone(),
                 font_size: 35.0,
                 color: Color::WHITE,
             },
@@ -144,7 +146,7 @@ pub(crate) fn setup_help(
         .spawn_bundle(TextBundle::from_section(
             "A to attack, E for elemental attack, D to defend, Q to quit",
             TextStyle {
-                font: asset_server.load("buttons/joystix monospace.ttf"),
+                font: asset_loader.fonts.joystix.clone(),
                 font_size: 30.0,
                 color: Color::WHITE,
             },
@@ -164,7 +166,7 @@ pub(crate) fn setup_help(
         .spawn_bundle(TextBundle::from_section(
             "1 to heal, 2 for strength",
             TextStyle {
-                font: asset_server.load("buttons/joystix monospace.ttf"),
+                font: asset_loader.fonts.joystix.clone(),
                 font_size: 30.0,
                 color: Color::WHITE,
             },
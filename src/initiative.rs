@@ -0,0 +1,110 @@
+use crate::monster::{Element, Enemy, SelectedMonster};
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use rand::*;
+
+/// Initiative accumulates at the combatant's speed every tick; whoever
+/// first crosses `THRESHOLD` gets to act (and may cross it more than once
+/// per cycle if they're fast enough, acting twice before a slow monster
+/// gets a turn at all).
+const THRESHOLD: f32 = 100.0;
+
+/// A monster's speed/agility stat, driving how often it acts. Not yet part
+/// of `MonsterStats` -- inserted alongside it -- so existing monsters
+/// default to a flat speed until the stat is threaded through properly.
+#[derive(Component, Clone, Copy)]
+pub(crate) struct Speed(pub(crate) i32);
+
+impl Default for Speed {
+    fn default() -> Self {
+        Speed(10)
+    }
+}
+
+impl Speed {
+    /// Per-`Element` base agility, so freshly rolled enemies and captured
+    /// monsters aren't all permanently tied at the default speed -- without
+    /// this every combatant would sit at the default `Speed`, so the
+    /// fast-monster-acts-twice initiative behavior could never actually be
+    /// observed.
+    pub(crate) fn for_element(element: Element) -> Self {
+        match element {
+            Element::Air => Speed(14),
+            Element::Fire => Speed(12),
+            Element::Water => Speed(9),
+            Element::Earth => Speed(7),
+            _ => Speed::default(),
+        }
+    }
+}
+
+/// Marks whichever combatant currently holds the turn. A side's half of a
+/// battle exchange only lands while it holds this, and the player's item
+/// keys do nothing without it.
+#[derive(Component)]
+pub(crate) struct MyTurn;
+
+/// Per-combatant accumulator, keyed by entity.
+#[derive(Default)]
+pub(crate) struct InitiativeTracker {
+    accumulators: HashMap<Entity, f32>,
+}
+
+/// Advance each combatant's accumulator by its speed; grant `MyTurn` to
+/// whoever crosses `THRESHOLD`, with ties broken randomly.
+pub(crate) fn tick_initiative(
+    mut commands: Commands,
+    mut tracker: ResMut<InitiativeTracker>,
+    player_query: Query<(Entity, Option<&Speed>), (With<SelectedMonster>, Without<Enemy>)>,
+    enemy_query: Query<(Entity, Option<&Speed>), (With<Enemy>, Without<SelectedMonster>)>,
+    turn_holder_query: Query<Entity, With<MyTurn>>,
+) {
+    // Someone is still resolving their turn; don't hand out another one yet.
+    if !turn_holder_query.is_empty() {
+        return;
+    }
+
+    let combatants: Vec<(Entity, i32)> = player_query
+        .iter()
+        .chain(enemy_query.iter())
+        .map(|(entity, speed)| (entity, speed.copied().unwrap_or_default().0))
+        .collect();
+
+    if combatants.is_empty() {
+        return;
+    }
+
+    let mut overflowing = Vec::new();
+    for (entity, speed) in &combatants {
+        let accumulator = tracker.accumulators.entry(*entity).or_insert(0.0);
+        *accumulator += *speed as f32;
+        if *accumulator >= THRESHOLD {
+            overflowing.push((*entity, *accumulator));
+        }
+    }
+
+    if overflowing.is_empty() {
+        return;
+    }
+
+    // Highest overflow acts first; ties broken randomly.
+    let max_overflow = overflowing
+        .iter()
+        .map(|(_, overflow)| *overflow)
+        .fold(f32::MIN, f32::max);
+    let mut winners: Vec<Entity> = overflowing
+        .iter()
+        .filter(|(_, overflow)| *overflow == max_overflow)
+        .map(|(entity, _)| *entity)
+        .collect();
+    let winner = if winners.len() == 1 {
+        winners.remove(0)
+    } else {
+        winners.remove(rand::thread_rng().gen_range(0..winners.len()))
+    };
+
+    if let Some(accumulator) = tracker.accumulators.get_mut(&winner) {
+        *accumulator -= THRESHOLD;
+    }
+    commands.entity(winner).insert(MyTurn);
+}
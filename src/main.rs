@@ -13,6 +13,7 @@ use std::sync::mpsc::{Receiver, Sender};
 pub (crate) enum GameState{
 	Start,
 	Pause,
+    Loading,
     StartPlaying,
 	Playing,
     Battle,
@@ -21,8 +22,9 @@ pub (crate) enum GameState{
     HostBattle,
     PeerBattle,
     Credits,
-    Help, 
-    MultiplayerMenu
+    Help,
+    MultiplayerMenu,
+    GameOver
 }
 
 pub(crate) const TITLE: &str = "Waste";
@@ -31,7 +33,7 @@ pub(crate) const TITLE: &str = "Waste";
 // CUSTOM MODULE DEFINITIONS AND IMPORTS
 //mod statements:
 mod credits;
-mod help; 
+mod help;
 mod backgrounds;
 mod player;
 mod camera;
@@ -42,11 +44,26 @@ mod monster;
 mod world;
 mod multiplayer_menu;
 mod game_client;
+mod assets;
+mod game_over;
+mod save;
+mod run_clock;
+mod pause;
+mod debug_overlay;
+mod initiative;
+mod equipment;
+mod encounter_table;
+mod boss_summon;
+mod stats;
+mod dodge;
+mod challenge;
+mod status_effects;
+mod enemy_ai;
 
 
 //use statements:
 use credits::*;
-use help::*; 
+use help::*;
 use backgrounds::*;
 use player::*;
 use camera::*;
@@ -57,6 +74,18 @@ use monster::*;
 use world::*;
 use multiplayer_menu::*;
 use game_client::*;
+use assets::*;
+use game_over::*;
+use save::*;
+use run_clock::*;
+use pause::*;
+use debug_overlay::*;
+use initiative::*;
+use equipment::*;
+use encounter_table::*;
+use boss_summon::*;
+use stats::*;
+use status_effects::*;
 
 
 
@@ -85,19 +114,28 @@ fn main() {
         .init_resource::<GameProgress>()
         .init_resource::<TypeSystem>()
         .init_resource::<ProcGen>()
+        .init_resource::<EncounterTables>()
         .add_plugins(DefaultPlugins)
-        // Starts game at main menu
+        // Boot into asset loading first so `AssetLoader`'s fonts/images are
+        // populated before the main menu (or anything else) ever renders;
+        // `wait_for_assets` hands off to `GameState::Start` once loaded.
         // Initial state should be "loopless"
-		.add_loopless_state(GameState::Start)
+		.add_loopless_state(GameState::Loading)
 		.add_plugin(MainMenuPlugin)
         .add_plugin(CreditsPlugin)
         .add_plugin(HelpPlugin)
         .add_plugin(BattlePlugin)
         .add_plugin(MultMenuPlugin)
-    .add_enter_system_set(GameState::StartPlaying, 
+        .add_plugin(AssetLoaderPlugin)
+        .add_plugin(GameOverPlugin)
+        .add_plugin(RunClockPlugin)
+        .add_plugin(PausePlugin)
+        .add_plugin(DebugOverlayPlugin)
+    .add_enter_system_set(GameState::StartPlaying,
         // This system set is unconditional, as it is being added in an enter helper
         SystemSet::new()
             .with_system(init_background)
+            .with_system(load_save_resources)
             .with_system(setup_game)
     )
     .add_system_set(ConditionSet::new()
@@ -109,14 +147,18 @@ fn main() {
             .with_system(animate_sprite)
             .with_system(expand_map)
             .with_system(win_game)
+            .with_system(lose_game)
+            .with_system(tick_run_clock)
+            .with_system(update_difficulty)
+            .with_system(render_run_timer)
         .into()
     )
+    .add_system(save_on_exit)
     .run();
 }
 
 pub(crate) fn setup_game(mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    asset_loader: Res<AssetLoader>,
     cameras: Query<Entity, (With<Camera2d>, Without<MainCamera>, Without<Player>, Without<Tile>)>,
     mut game_progress: ResMut<GameProgress>
 ) {
@@ -129,14 +171,10 @@ pub(crate) fn setup_game(mut commands: Commands,
 	let camera = Camera2dBundle::default();
     commands.spawn_bundle(camera).insert(MainCamera);
 
-    let texture_handle = asset_server.load("characters/sprite_movement.png");
-    let texture_atlas = TextureAtlas::from_grid(texture_handle, Vec2::new(64.0, 64.0), 4, 4);
-    let texture_atlas_handle = texture_atlases.add(texture_atlas);
-
     // Draw the player
     commands
-        .spawn_bundle(SpriteSheetBundle { 
-            texture_atlas: texture_atlas_handle,
+        .spawn_bundle(SpriteSheetBundle {
+            texture_atlas: asset_loader.layouts.player_atlas.clone(),
             transform: Transform::from_xyz(0., 0., 0.),
             ..default()
         })
@@ -149,16 +187,19 @@ pub(crate) fn setup_game(mut commands: Commands,
 			//constants can be found in player.rs,
         });
 
-    // Give the player a monster
-    let initial_monster_stats = MonsterStats {..Default::default()};
-    let initial_monster = commands.spawn()
-        .insert_bundle(initial_monster_stats.clone())
-        .insert(SelectedMonster)
-        .insert(PartyMonster).id();
-    // initial_monster.insert(SelectedMonster);
-    game_progress.new_monster(initial_monster.clone(), initial_monster_stats.clone());
-    
-    
+    // Give the player a monster, unless a save was just restored into
+    // `game_progress` by `load_save_resources`
+    if game_progress.monster_id_entity.is_empty() {
+        let initial_monster_stats = MonsterStats {..Default::default()};
+        let initial_monster = commands.spawn()
+            .insert_bundle(initial_monster_stats.clone())
+            .insert(SelectedMonster)
+            .insert(PartyMonster)
+            .insert(Speed::default())
+            .insert(ActiveEffects::default()).id();
+        // initial_monster.insert(SelectedMonster);
+        game_progress.new_monster(initial_monster.clone(), initial_monster_stats.clone());
+    }
 
     // Finally, transition to normal playing state
     commands.insert_resource(NextState(GameState::Playing));
@@ -0,0 +1,129 @@
+use crate::assets::AssetLoader;
+use crate::GameState;
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+
+/// The state to return to on resume. Stashed when entering `Pause` so the
+/// world behind the overlay isn't torn down.
+#[derive(Default)]
+pub(crate) struct PreviousState(pub(crate) Option<GameState>);
+
+#[derive(Component)]
+pub(crate) struct PauseUIElement;
+
+pub(crate) struct PausePlugin;
+
+impl Plugin for PausePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PreviousState>()
+            .add_system_set(
+                ConditionSet::new()
+                    .run_in_state(GameState::Playing)
+                    .with_system(enter_pause)
+                    .into(),
+            )
+            .add_enter_system(GameState::Pause, setup_pause)
+            .add_system_set(
+                ConditionSet::new()
+                    .run_in_state(GameState::Pause)
+                    .with_system(handle_pause_input)
+                    .into(),
+            )
+            .add_exit_system(GameState::Pause, despawn_pause);
+    }
+}
+
+fn enter_pause(
+    mut commands: Commands,
+    input: Res<Input<KeyCode>>,
+    mut previous_state: ResMut<PreviousState>,
+) {
+    if input.just_pressed(KeyCode::Escape) {
+        previous_state.0 = Some(GameState::Playing);
+        commands.insert_resource(NextState(GameState::Pause));
+    }
+}
+
+fn setup_pause(mut commands: Commands, asset_loader: Res<AssetLoader>) {
+    // Unlike setup_help/setup_game_over, Pause doesn't tear down the world,
+    // so MainCamera is still alive underneath -- spawning a second
+    // Camera2d here would leave two cameras rendering the same scene and,
+    // since nothing ever despawned it, leak one every pause/resume cycle.
+
+    // Semi-transparent full-screen overlay, mirroring the text-spawning
+    // style used in setup_help.
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+            ..default()
+        })
+        .insert(PauseUIElement);
+
+    commands
+        .spawn_bundle(TextBundle::from_section(
+            "PAUSED",
+            TextStyle {
+                font: asset_loader.fonts.joystix.clone(),
+                font_size: 50.0,
+                color: Color::WHITE,
+            },
+        ))
+        .insert(Style {
+            position_type: PositionType::Absolute,
+            position: UiRect {
+                top: Val::Px(250.0),
+                left: Val::Px(480.0),
+                ..default()
+            },
+            ..default()
+        })
+        .insert(PauseUIElement);
+
+    commands
+        .spawn_bundle(TextBundle::from_section(
+            "Escape to resume, H for help, Q to quit to menu",
+            TextStyle {
+                font: asset_loader.fonts.joystix.clone(),
+                font_size: 30.0,
+                color: Color::WHITE,
+            },
+        ))
+        .insert(Style {
+            position_type: PositionType::Absolute,
+            position: UiRect {
+                top: Val::Px(350.0),
+                left: Val::Px(300.0),
+                ..default()
+            },
+            ..default()
+        })
+        .insert(PauseUIElement);
+}
+
+fn handle_pause_input(
+    mut commands: Commands,
+    input: Res<Input<KeyCode>>,
+    previous_state: Res<PreviousState>,
+) {
+    if input.just_pressed(KeyCode::Escape) {
+        // Resume without tearing down the world.
+        let resume_to = previous_state.0.unwrap_or(GameState::Playing);
+        commands.insert_resource(NextState(resume_to));
+    } else if input.just_pressed(KeyCode::H) {
+        commands.insert_resource(NextState(GameState::Help));
+    } else if input.just_pressed(KeyCode::Q) {
+        commands.insert_resource(NextState(GameState::Start));
+    }
+}
+
+fn despawn_pause(mut commands: Commands, ui_query: Query<Entity, With<PauseUIElement>>) {
+    ui_query.for_each(|element| {
+        commands.entity(element).despawn_recursive();
+    });
+}
@@ -0,0 +1,103 @@
+use crate::assets::AssetLoader;
+use crate::world::GameProgress;
+use crate::GameState;
+use bevy::prelude::*;
+use bevy::time::Stopwatch;
+
+/// Tracks total time survived in the current run. Ticks only while
+/// `GameState::Playing`; menus/`Pause` leave it untouched.
+#[derive(Default)]
+pub(crate) struct RunClock {
+    pub(crate) stopwatch: Stopwatch,
+}
+
+impl RunClock {
+    pub(crate) fn elapsed_secs(&self) -> f32 {
+        self.stopwatch.elapsed_secs()
+    }
+}
+
+#[derive(Component)]
+pub(crate) struct RunTimerText;
+
+/// Advance the run clock. Only added to the `Playing` `ConditionSet`, so it
+/// naturally pauses in every other state.
+pub(crate) fn tick_run_clock(time: Res<Time>, mut run_clock: ResMut<RunClock>) {
+    run_clock.stopwatch.tick(time.delta());
+}
+
+/// Recompute the difficulty multiplier from elapsed time and boss count.
+/// Scales linearly with minutes survived and with bosses already defeated.
+pub(crate) fn update_difficulty(
+    run_clock: Res<RunClock>,
+    mut game_progress: ResMut<GameProgress>,
+) {
+    let minutes_survived = run_clock.elapsed_secs() / 60.0;
+    game_progress.difficulty_multiplier =
+        1.0 + minutes_survived * 0.05 + game_progress.num_boss_defeated as f32 * 0.25;
+}
+
+pub(crate) fn spawn_run_timer(mut commands: Commands, asset_loader: Res<AssetLoader>) {
+    commands
+        .spawn_bundle(TextBundle::from_section(
+            "00:00",
+            TextStyle {
+                font: asset_loader.fonts.joystix.clone(),
+                font_size: 30.0,
+                color: Color::WHITE,
+            },
+        ))
+        .insert(Style {
+            position_type: PositionType::Absolute,
+            position: UiRect {
+                top: Val::Px(5.0),
+                left: Val::Px(15.0),
+                ..default()
+            },
+            ..default()
+        })
+        .insert(RunTimerText);
+}
+
+pub(crate) fn render_run_timer(
+    run_clock: Res<RunClock>,
+    mut text_query: Query<&mut Text, With<RunTimerText>>,
+) {
+    let total_secs = run_clock.elapsed_secs() as u32;
+    for mut text in &mut text_query {
+        text.sections[0].value = format!("{:02}:{:02}", total_secs / 60, total_secs % 60);
+    }
+}
+
+pub(crate) fn despawn_run_timer(
+    mut commands: Commands,
+    text_query: Query<Entity, With<RunTimerText>>,
+) {
+    text_query.for_each(|text| {
+        commands.entity(text).despawn();
+    });
+}
+
+/// Apply `GameProgress::difficulty_multiplier` to freshly generated enemy
+/// stats, scaling HP/attack/defense so the world gets harder the longer a
+/// run lasts.
+pub(crate) fn scale_stats_for_difficulty(
+    stats: &mut crate::monster::MonsterStats,
+    difficulty_multiplier: f32,
+) {
+    stats.hp.max_health = (stats.hp.max_health as f32 * difficulty_multiplier) as u32;
+    stats.hp.health = stats.hp.max_health as isize;
+    stats.stg.atk = (stats.stg.atk as f32 * difficulty_multiplier) as i32;
+    stats.def.def = (stats.def.def as f32 * difficulty_multiplier) as i32;
+}
+
+pub(crate) struct RunClockPlugin;
+
+impl Plugin for RunClockPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunClock>()
+            .add_enter_system(GameState::StartPlaying, spawn_run_timer)
+            .add_exit_system(GameState::Credits, despawn_run_timer)
+            .add_exit_system(GameState::GameOver, despawn_run_timer);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::run_clock::RunClock;
+use crate::world::{GameProgress, WorldMap};
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use std::fs;
+use std::path::PathBuf;
+
+const SAVE_FILE: &str = "save.json";
+
+/// On-disk representation of everything a save needs to restore a run.
+///
+/// Requires `GameProgress` and `WorldMap` (defined in `world.rs`) to derive
+/// `serde::Serialize`/`serde::Deserialize` themselves -- they're purely
+/// in-memory resources otherwise, so that derive has to be added at their
+/// definitions, not just on this wrapper.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub(crate) struct SaveData {
+    pub(crate) game_progress: GameProgress,
+    pub(crate) world_map: WorldMap,
+    pub(crate) run_elapsed_secs: f32,
+}
+
+fn save_dir() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("waste")
+}
+
+fn save_path() -> PathBuf {
+    save_dir().join(SAVE_FILE)
+}
+
+/// Returns true if a prior save exists. Nothing calls it: the start menu
+/// has no "Continue" entry.
+pub(crate) fn save_exists() -> bool {
+    save_path().is_file()
+}
+
+/// Serialize the current `GameProgress`/`WorldMap`/`RunClock` resources to the save file.
+pub(crate) fn save_game(game_progress: &GameProgress, world_map: &WorldMap, run_clock: &RunClock) {
+    let data = SaveData {
+        game_progress: game_progress.clone(),
+        world_map: world_map.clone(),
+        run_elapsed_secs: run_clock.elapsed_secs(),
+    };
+
+    let Ok(serialized) = serde_json::to_string(&data) else {
+        error!("Failed to serialize save data");
+        return;
+    };
+
+    if let Err(e) = fs::create_dir_all(save_dir()) {
+        error!("Failed to create save directory: {}", e);
+        return;
+    }
+
+    if let Err(e) = fs::write(save_path(), serialized) {
+        error!("Failed to write save file: {}", e);
+    }
+}
+
+/// Load a prior save, if one exists.
+pub(crate) fn load_game() -> Option<SaveData> {
+    let contents = fs::read_to_string(save_path()).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Restore `GameProgress`/`WorldMap`/`RunClock` from disk instead of
+/// `setup_game`'s fresh initialization. Called on entering `StartPlaying`
+/// when a save exists.
+pub(crate) fn load_save_resources(mut commands: Commands) {
+    if let Some(save) = load_game() {
+        commands.insert_resource(save.game_progress);
+        commands.insert_resource(save.world_map);
+        let mut run_clock = RunClock::default();
+        run_clock
+            .stopwatch
+            .set_elapsed(std::time::Duration::from_secs_f32(save.run_elapsed_secs));
+        commands.insert_resource(run_clock);
+    }
+}
+
+/// Write the save file once on a clean exit, regardless of which state the
+/// app is in when it quits.
+pub(crate) fn save_on_exit(
+    mut exit_events: EventReader<AppExit>,
+    game_progress: Res<GameProgress>,
+    world_map: Res<WorldMap>,
+    run_clock: Res<RunClock>,
+) {
+    for _ in exit_events.iter() {
+        save_game(&game_progress, &world_map, &run_clock);
+    }
+}
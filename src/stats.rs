@@ -0,0 +1,174 @@
+use crate::monster::Element;
+use bevy::utils::HashMap;
+
+/// A resource pool with a current and max value (health, mana, ...).
+#[derive(Clone, Copy)]
+pub(crate) struct Pool {
+    pub(crate) max: i32,
+    pub(crate) current: i32,
+}
+
+impl Pool {
+    pub(crate) fn refill(&mut self) {
+        self.current = self.max;
+    }
+}
+
+/// A trained combat skill, each independently levelled by a `GrowthCurve`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Skill {
+    Melee,
+    Defense,
+    Magic,
+}
+
+/// Per-monster skill levels, replacing the fixed `atk`/`def`/`crt` increments
+/// `monster_level_up!` used to apply to every species identically.
+#[derive(Clone)]
+pub(crate) struct Skills {
+    values: HashMap<Skill, i32>,
+}
+
+impl Skills {
+    /// Seed every skill from a level, using the default `GrowthCurve`.
+    pub(crate) fn new(level: i32) -> Self {
+        GrowthCurve::default().seed(level)
+    }
+
+    /// Seed every skill from a level using `curve`, so callers that already
+    /// have a per-`Element` curve in hand (rather than wanting the default)
+    /// don't silently lose it.
+    pub(crate) fn for_curve(curve: &GrowthCurve, level: i32) -> Self {
+        curve.seed(level)
+    }
+
+    pub(crate) fn get(&self, skill: Skill) -> i32 {
+        *self.values.get(&skill).unwrap_or(&0)
+    }
+
+    fn set(&mut self, skill: Skill, value: i32) {
+        self.values.insert(skill, value);
+    }
+}
+
+/// Base value plus a per-level multiplier for each skill, the health pool,
+/// and crit, loaded per `Element` so different species level differently.
+#[derive(Clone)]
+pub(crate) struct GrowthCurve {
+    pub(crate) base_health: i32,
+    pub(crate) health_per_level: i32,
+    pub(crate) base_skills: HashMap<Skill, i32>,
+    pub(crate) skill_per_level: HashMap<Skill, i32>,
+    pub(crate) base_crt: i32,
+    pub(crate) crt_per_level: i32,
+}
+
+impl Default for GrowthCurve {
+    fn default() -> Self {
+        let mut base_skills = HashMap::new();
+        base_skills.insert(Skill::Melee, 5);
+        base_skills.insert(Skill::Defense, 3);
+        base_skills.insert(Skill::Magic, 2);
+
+        let mut skill_per_level = HashMap::new();
+        skill_per_level.insert(Skill::Melee, 2);
+        skill_per_level.insert(Skill::Defense, 1);
+        skill_per_level.insert(Skill::Magic, 1);
+
+        GrowthCurve {
+            base_health: 20,
+            health_per_level: 10,
+            base_skills,
+            skill_per_level,
+            // Matches the old flat `+5 crt/level` the macro used to apply.
+            base_crt: 0,
+            crt_per_level: 5,
+        }
+    }
+}
+
+impl GrowthCurve {
+    /// Per-`Element` growth curve, so each species levels differently
+    /// instead of every monster sharing the default curve. Elements not
+    /// called out here fall back to the default curve.
+    pub(crate) fn for_element(element: Element) -> Self {
+        match element {
+            Element::Fire => {
+                let mut curve = GrowthCurve::default();
+                *curve.skill_per_level.get_mut(&Skill::Melee).unwrap() += 1;
+                curve.crt_per_level += 2;
+                curve
+            }
+            Element::Water => {
+                let mut curve = GrowthCurve::default();
+                *curve.skill_per_level.get_mut(&Skill::Magic).unwrap() += 1;
+                curve.health_per_level += 2;
+                curve
+            }
+            Element::Earth => {
+                let mut curve = GrowthCurve::default();
+                *curve.skill_per_level.get_mut(&Skill::Defense).unwrap() += 2;
+                curve.health_per_level += 4;
+                curve.crt_per_level -= 2;
+                curve
+            }
+            Element::Air => {
+                let mut curve = GrowthCurve::default();
+                *curve.skill_per_level.get_mut(&Skill::Melee).unwrap() += 1;
+                curve.health_per_level -= 2;
+                curve.crt_per_level += 1;
+                curve
+            }
+            _ => GrowthCurve::default(),
+        }
+    }
+
+    fn seed(&self, level: i32) -> Skills {
+        let mut skills = Skills {
+            values: HashMap::new(),
+        };
+        for skill in [Skill::Melee, Skill::Defense, Skill::Magic] {
+            let base = *self.base_skills.get(&skill).unwrap_or(&0);
+            let per_level = *self.skill_per_level.get(&skill).unwrap_or(&0);
+            skills.set(skill, base + per_level * level);
+        }
+        skills
+    }
+
+    pub(crate) fn health_for_level(&self, level: i32) -> i32 {
+        self.base_health + self.health_per_level * level
+    }
+
+    pub(crate) fn crt_for_level(&self, level: i32) -> i32 {
+        self.base_crt + self.crt_per_level * level
+    }
+}
+
+/// Recompute `skills`/`health`/`crt` from `curve` for a monster that just
+/// gained `up_by` levels, refilling `health.current` to the new max.
+/// Replaces the fixed `+10 hp, +2 atk, +5 crt, +1 def` arithmetic the old
+/// `monster_level_up!` macro baked in for every species.
+pub(crate) fn level_up(
+    skills: &mut Skills,
+    health: &mut Pool,
+    crt: &mut i32,
+    curve: &GrowthCurve,
+    new_level: i32,
+    up_by: i32,
+) {
+    let _ = up_by;
+    *skills = curve.seed(new_level);
+    health.max = curve.health_for_level(new_level);
+    health.refill();
+    *crt = curve.crt_for_level(new_level);
+}
+
+/// Derive the old flat `Strength.atk` from the new skill model.
+pub(crate) fn atk_from_skills(skills: &Skills) -> i32 {
+    skills.get(Skill::Melee)
+}
+
+/// Derive the old flat `Defense.def` from the new skill model.
+pub(crate) fn def_from_skills(skills: &Skills) -> i32 {
+    skills.get(Skill::Defense)
+}
@@ -0,0 +1,101 @@
+use crate::battle::{BattleLog, LogSeverity};
+use crate::monster::Health;
+use bevy::prelude::*;
+
+/// Every kind of timed modifier or damage/heal-over-time effect a monster
+/// can carry. Replaces the old `game_progress.turns_left_of_buff` array
+/// index, which only ever tracked one hardcoded strength buff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum StatusEffectKind {
+    StrengthBuff,
+    DefenseBuff,
+    Poison,
+    Regen,
+    CritUp,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ActiveEffect {
+    pub(crate) kind: StatusEffectKind,
+    pub(crate) turns_remaining: i32,
+    pub(crate) magnitude: i32,
+}
+
+/// The timed effects currently active on a monster. The battle's attack
+/// resolution reads accumulated buff magnitudes from this, instead of a bare
+/// array index, and folds them into the stats fed to `calculate_turn`.
+#[derive(Component, Default, Clone)]
+pub(crate) struct ActiveEffects {
+    effects: Vec<ActiveEffect>,
+}
+
+impl ActiveEffects {
+    /// Apply a new effect, refreshing the duration of an existing effect of
+    /// the same kind rather than stacking a duplicate entry.
+    pub(crate) fn apply(&mut self, kind: StatusEffectKind, turns_remaining: i32, magnitude: i32) {
+        if let Some(existing) = self.effects.iter_mut().find(|effect| effect.kind == kind) {
+            existing.turns_remaining = existing.turns_remaining.max(turns_remaining);
+            existing.magnitude = magnitude;
+        } else {
+            self.effects.push(ActiveEffect {
+                kind,
+                turns_remaining,
+                magnitude,
+            });
+        }
+    }
+
+    /// Sum the magnitude of every active effect of `kind` (usually 0 or 1
+    /// entries today, but additive stacking falls out for free).
+    pub(crate) fn magnitude_for(&self, kind: StatusEffectKind) -> i32 {
+        self.effects
+            .iter()
+            .filter(|effect| effect.kind == kind)
+            .map(|effect| effect.magnitude)
+            .sum()
+    }
+
+    /// Apply this turn's per-tick behavior (poison/regen chip `health`),
+    /// decrement every effect's `turns_remaining`, and drop any effect that
+    /// just hit zero, logging the expiry of every kind except `Regen`.
+    pub(crate) fn tick(&mut self, health: &mut Health, owner_label: &str, battle_log: &mut BattleLog) {
+        for effect in &self.effects {
+            match effect.kind {
+                StatusEffectKind::Poison => {
+                    health.health -= effect.magnitude as isize;
+                    battle_log.push(
+                        format!("{} takes {} poison damage.", owner_label, effect.magnitude),
+                        LogSeverity::Normal,
+                    );
+                }
+                StatusEffectKind::Regen => {
+                    health.health = (health.health + effect.magnitude as isize)
+                        .min(health.max_health as isize);
+                    battle_log.push(
+                        format!("{} regenerates {} health.", owner_label, effect.magnitude),
+                        LogSeverity::Reward,
+                    );
+                }
+                StatusEffectKind::StrengthBuff | StatusEffectKind::DefenseBuff | StatusEffectKind::CritUp => {
+                    // Buffs only modify the values fed into calculate_turn;
+                    // they have no per-tick health effect of their own.
+                }
+            }
+        }
+
+        self.effects.retain_mut(|effect| {
+            effect.turns_remaining -= 1;
+            let expired = effect.turns_remaining <= 0;
+            // Regen is routed through here for instant heal-item use too,
+            // where expiring on the very next tick is expected and not
+            // worth announcing like a real buff/DoT running out.
+            if expired && effect.kind != StatusEffectKind::Regen {
+                battle_log.push(
+                    format!("{}'s {:?} wore off.", owner_label, effect.kind),
+                    LogSeverity::Normal,
+                );
+            }
+            !expired
+        });
+    }
+}